@@ -0,0 +1,54 @@
+//*****************************************************************************
+// (C) 2018, Stefan Korner, Austria                                           *
+//                                                                            *
+// The Space Rust Library is free software; you can redistribute it and/or    *
+// modify it under the terms of the MIT License as published by the           *
+// Massachusetts Institute of Technology.                                     *
+//                                                                            *
+// The Space Rust Library is distributed in the hope that it will be useful,  *
+// but WITHOUT ANY WARRANTY; without even the implied warranty of             *
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the MIT License   *
+// for more details.                                                          *
+//*****************************************************************************
+// Benchmarks - Cyclic Redundancy Check (CRC)                                 *
+//                                                                            *
+// Compares the CRC-16 implementations, run with: cargo bench                 *
+//*****************************************************************************
+#[macro_use]
+extern crate criterion;
+extern crate space_rust_library;
+
+use criterion::{black_box, BenchmarkId, Criterion, Throughput};
+use space_rust_library::util::crc;
+
+///////////////
+// constants //
+///////////////
+
+// typical TC frame, TM packet and TM frame sizes
+const SIZES: [usize; 3] = [256, 1024, 4096];
+
+////////////////
+// benchmarks //
+////////////////
+
+fn bench_crc16(c: &mut Criterion) {
+    let mut group = c.benchmark_group("crc16");
+    for size in SIZES.iter() {
+        let bytes: Vec<u8> = (0..*size).map(|i| (i * 157 + 13) as u8).collect();
+        group.throughput(Throughput::Bytes(*size as u64));
+        group.bench_with_input(BenchmarkId::new("bitwise", size), &bytes, |b, bytes|
+            b.iter(|| crc::calculate16_bitwise(black_box(bytes), bytes.len())));
+        group.bench_with_input(BenchmarkId::new("table", size), &bytes, |b, bytes|
+            b.iter(|| crc::calculate16_table(black_box(bytes), bytes.len())));
+        group.bench_with_input(BenchmarkId::new("slice8", size), &bytes, |b, bytes|
+            b.iter(|| crc::calculate16_slice8(black_box(bytes), bytes.len())));
+        let generic_crc = crc::Crc::new(crc::CRC16_CCITT_FALSE);
+        group.bench_with_input(BenchmarkId::new("generic", size), &bytes, |b, bytes|
+            b.iter(|| generic_crc.checksum(black_box(bytes))));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_crc16);
+criterion_main!(benches);
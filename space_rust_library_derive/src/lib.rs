@@ -0,0 +1,248 @@
+//*****************************************************************************
+// (C) 2018, Stefan Korner, Austria                                           *
+//                                                                            *
+// The Space Rust Library is free software; you can redistribute it and/or    *
+// modify it under the terms of the MIT License as published by the           *
+// Massachusetts Institute of Technology.                                     *
+//                                                                            *
+// The Space Rust Library is distributed in the hope that it will be useful,  *
+// but WITHOUT ANY WARRANTY; without even the implied warranty of             *
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the MIT License   *
+// for more details.                                                          *
+//*****************************************************************************
+// Derive macros                                                              *
+//                                                                            *
+// #[derive(DataUnit)] turns a struct with a du::HybridVector buffer and      *
+// attributed marker fields into a data unit with typed accessors:            *
+// #[bits(bit_pos, bit_length)]    -> get/set_<name>_field with u32           *
+// #[unsigned(byte_pos, byte_len)] -> get/set_<name>_field with u32           *
+// #[cuc_time(byte_pos, p_field)]  -> get/set_<name>_field with CUC time      *
+// The marker fields are initialised with Default::default().                 *
+// #[data_unit(no_constructors)] on the struct skips the generated            *
+// constructors, e.g. when they must initialise the buffer contents.          *
+//*****************************************************************************
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+
+///////////////
+// constants //
+///////////////
+
+const FIELD_ATTRIBUTES: [&str; 3] = ["bits", "unsigned", "cuc_time"];
+
+///////////////////
+// derive macros //
+///////////////////
+
+#[proc_macro_derive(DataUnit, attributes(data_unit, bits, unsigned, cuc_time))]
+pub fn derive_data_unit(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    match expand_data_unit(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into()
+    }
+}
+
+//////////////////////
+// helper functions //
+//////////////////////
+
+fn expand_data_unit(input: &syn::DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let fields = match input.data {
+        syn::Data::Struct(syn::DataStruct {fields: syn::Fields::Named(ref fields), ..}) => &fields.named,
+        _ => return Err(syn::Error::new(input.span(), "DataUnit can only be derived for structs with named fields"))
+    };
+    // the lifetime of wrapped buffers
+    let lifetime = match input.generics.lifetimes().next() {
+        Some(lifetime_def) => lifetime_def.lifetime.clone(),
+        None => return Err(syn::Error::new(input.generics.span(), "DataUnit needs a lifetime parameter for the buffer"))
+    };
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let with_constructors = !has_no_constructors(input)?;
+    // separate the buffer from the marker fields
+    let mut buffer = None;
+    let mut markers = Vec::new();
+    let mut accessors = Vec::new();
+    for field in fields {
+        let field_name = field.ident.clone().unwrap();
+        let attrs: Vec<&syn::Attribute> = field.attrs.iter().
+            filter(|attr| FIELD_ATTRIBUTES.iter().any(|ident| attr.path.is_ident(ident))).
+            collect();
+        if attrs.is_empty() {
+            if is_hybrid_vector(&field.ty) && buffer.is_none() {
+                buffer = Some(field_name);
+                continue;
+            }
+            return Err(syn::Error::new(field.span(), "field needs a #[bits], #[unsigned] or #[cuc_time] attribute"));
+        }
+        if attrs.len() > 1 {
+            return Err(syn::Error::new(field.span(), "field has more than one layout attribute"));
+        }
+        accessors.push(expand_accessor(&field_name, attrs[0])?);
+        markers.push(field_name);
+    }
+    let buffer = match buffer {
+        Some(buffer) => buffer,
+        None => return Err(syn::Error::new(input.span(), "DataUnit needs a du::HybridVector buffer field"))
+    };
+    let markers = &markers;
+    let constructor = |init: TokenStream2| {
+        quote! {
+            #name {
+                #buffer: #init,
+                #(#markers: ::std::default::Default::default()),*
+            }
+        }
+    };
+    let new = constructor(quote! {::space_rust_library::util::du::HybridVector::new()});
+    let new_clone = constructor(quote! {::space_rust_library::util::du::HybridVector::new_owner(value.to_vec())});
+    let new_alloc = constructor(quote! {::space_rust_library::util::du::HybridVector::new_alloc(size)});
+    let new_owner = constructor(quote! {::space_rust_library::util::du::HybridVector::new_owner(value)});
+    let new_read_only = constructor(quote! {::space_rust_library::util::du::HybridVector::new_read_only(reference)});
+    let new_read_write = constructor(quote! {::space_rust_library::util::du::HybridVector::new_read_write(reference)});
+    let constructors = if with_constructors {
+        quote! {
+            pub fn new() -> Self {
+                #new
+            }
+            pub fn new_clone(value: &[u8]) -> Self {
+                #new_clone
+            }
+            pub fn new_alloc(size: usize) -> Self {
+                #new_alloc
+            }
+            pub fn new_owner(value: Vec<u8>) -> Self {
+                #new_owner
+            }
+            pub fn new_read_only(reference: &#lifetime [u8]) -> Self {
+                #new_read_only
+            }
+            pub fn new_read_write(reference: &#lifetime mut [u8]) -> Self {
+                #new_read_write
+            }
+        }
+    } else {
+        quote! {}
+    };
+    Ok(quote! {
+        impl #impl_generics ::std::ops::Index<usize> for #name #ty_generics #where_clause {
+            type Output = u8;
+            fn index(&self, pos: usize) -> &u8 {
+                ::space_rust_library::util::du::DUintf::at(self, pos)
+            }
+        }
+
+        impl #impl_generics ::std::ops::IndexMut<usize> for #name #ty_generics #where_clause {
+            fn index_mut(&mut self, pos: usize) -> &mut u8 {
+                ::space_rust_library::util::du::DUintf::at_mut(self, pos)
+            }
+        }
+
+        impl #impl_generics ::space_rust_library::util::du::DUintf for #name #ty_generics #where_clause {
+            fn buffer_read_only(&self) -> &[u8] {
+                self.#buffer.read_only()
+            }
+            fn buffer_read_write(&mut self) -> &mut [u8] {
+                self.#buffer.read_write()
+            }
+            fn try_buffer_read_write(&mut self) ->
+                Result<&mut [u8], ::space_rust_library::util::exception::Exception> {
+                self.#buffer.try_read_write()
+            }
+            fn resize(&mut self, new_size: usize) {
+                self.#buffer.resize(new_size);
+            }
+            fn try_resize(&mut self, new_size: usize) ->
+                Result<(), ::space_rust_library::util::exception::Exception> {
+                self.#buffer.try_resize(new_size)
+            }
+            fn splice(&mut self, range: ::std::ops::Range<usize>, bytes: &[u8]) ->
+                Result<(), ::space_rust_library::util::exception::Exception> {
+                self.#buffer.splice(range, bytes)
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #constructors
+            #(#accessors)*
+        }
+    })
+}
+
+// generates the getter and setter for an attributed marker field
+fn expand_accessor(field_name: &syn::Ident, attr: &syn::Attribute) -> syn::Result<TokenStream2> {
+    let args = attr.parse_args_with(Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated)?;
+    if args.len() != 2 {
+        return Err(syn::Error::new(attr.span(), "layout attribute needs 2 arguments"));
+    }
+    let pos = &args[0];
+    let arg = &args[1];
+    let getter = syn::Ident::new(&format!("get_{}_field", field_name), field_name.span());
+    let setter = syn::Ident::new(&format!("set_{}_field", field_name), field_name.span());
+    let accessor = if attr.path.is_ident("bits") {
+        quote! {
+            pub fn #getter(&self) ->
+                Result<u32, ::space_rust_library::util::exception::Exception> {
+                ::space_rust_library::util::du::DUintf::get_bits(self, #pos, #arg)
+            }
+            pub fn #setter(&mut self, value: u32) ->
+                Result<(), ::space_rust_library::util::exception::Exception> {
+                ::space_rust_library::util::du::DUintf::set_bits(self, #pos, #arg, value)
+            }
+        }
+    } else if attr.path.is_ident("unsigned") {
+        quote! {
+            pub fn #getter(&self) ->
+                Result<u32, ::space_rust_library::util::exception::Exception> {
+                ::space_rust_library::util::du::DUintf::get_unsigned(self, #pos, #arg)
+            }
+            pub fn #setter(&mut self, value: u32) ->
+                Result<(), ::space_rust_library::util::exception::Exception> {
+                ::space_rust_library::util::du::DUintf::set_unsigned(self, #pos, #arg, value)
+            }
+        }
+    } else {
+        quote! {
+            pub fn #getter(&self) ->
+                Result<::space_rust_library::ccsds::cuc_time::Time, ::space_rust_library::util::exception::Exception> {
+                ::space_rust_library::ccsds::c_packet::get_cuc_time(self, #pos, #arg)
+            }
+            pub fn #setter(&mut self, value: ::space_rust_library::ccsds::cuc_time::Time) ->
+                Result<(), ::space_rust_library::util::exception::Exception> {
+                ::space_rust_library::ccsds::c_packet::set_cuc_time(self, #pos, #arg, value)
+            }
+        }
+    };
+    Ok(accessor)
+}
+
+// evaluates the #[data_unit(...)] attribute of the struct
+fn has_no_constructors(input: &syn::DeriveInput) -> syn::Result<bool> {
+    let mut no_constructors = false;
+    for attr in input.attrs.iter().filter(|attr| attr.path.is_ident("data_unit")) {
+        let option: syn::Ident = attr.parse_args()?;
+        if option != "no_constructors" {
+            return Err(syn::Error::new(option.span(), "unknown data_unit option, expected no_constructors"));
+        }
+        no_constructors = true;
+    }
+    Ok(no_constructors)
+}
+
+// checks if the type is a (path qualified) HybridVector
+fn is_hybrid_vector(ty: &syn::Type) -> bool {
+    match *ty {
+        syn::Type::Path(ref type_path) => type_path.path.segments.last().
+            is_some_and(|segment| segment.ident == "HybridVector"),
+        _ => false
+    }
+}
@@ -0,0 +1,187 @@
+//*****************************************************************************
+// (C) 2018, Stefan Korner, Austria                                           *
+//                                                                            *
+// The Space Rust Library is free software; you can redistribute it and/or    *
+// modify it under the terms of the MIT License as published by the           *
+// Massachusetts Institute of Technology.                                     *
+//                                                                            *
+// The Space Rust Library is distributed in the hope that it will be useful,  *
+// but WITHOUT ANY WARRANTY; without even the implied warranty of             *
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the MIT License   *
+// for more details.                                                          *
+//*****************************************************************************
+// ASCII TIME - CCSDS ASCII time codes A and B                                *
+//                                                                            *
+// Code A: YYYY-MM-DDThh:mm:ss.d->dZ ... month/day of month                   *
+// Code B: YYYY-DDDThh:mm:ss.d->dZ ..... day of year                          *
+// The seconds fraction (any number of digits) and the Z are optional.        *
+// Conversions are from/to time::Timespec (from Crate time), UTC is assumed.  *
+//*****************************************************************************
+use std::cmp;
+use time;
+use util::asd_time;
+use util::exception;
+
+///////////////
+// constants //
+///////////////
+// date and time part without seconds fraction, 'd' is a digit
+const CODE_A_PATTERN: &str = "dddd-dd-ddTdd:dd:dd";
+const CODE_B_PATTERN: &str = "dddd-dddTdd:dd:dd";
+
+//////////////////////
+// helper functions //
+//////////////////////
+
+// seconds fraction with the given number of digits (incl. the '.')
+fn get_fraction_str(nsec: i32, fraction_digits: usize) -> String {
+    if fraction_digits == 0 {
+        return String::new();
+    }
+    let mut fraction = format!(".{:09}", nsec);
+    fraction.truncate(fraction_digits + 1);
+    while fraction.len() < (fraction_digits + 1) {
+        fraction.push('0');
+    }
+    fraction
+}
+
+// splits the string into seconds part and nano seconds,
+// also returns the number of fraction digits
+fn split_fraction<'a>(time_str: &'a str, pattern: &str) ->
+    Result<(&'a str, i32, usize), exception::Exception> {
+    let trimmed_str = time_str.strip_suffix('Z').unwrap_or(time_str);
+    let seconds_len = pattern.len();
+    if (trimmed_str.len() < seconds_len) || !trimmed_str.is_char_boundary(seconds_len) {
+        return Err(exception::parse_error(&format!("parse error: invalid string length {}", time_str.len()), time_str));
+    }
+    let seconds_part = &trimmed_str[..seconds_len];
+    let pattern_ok = seconds_part.bytes().zip(pattern.bytes()).all(|(character, expected)|
+        if expected == b'd' {character.is_ascii_digit()} else {character == expected});
+    if !pattern_ok {
+        return Err(exception::parse_error("parse error in seconds part", time_str));
+    }
+    let seconds_fraction = &trimmed_str[seconds_len..];
+    if seconds_fraction.is_empty() {
+        return Ok((seconds_part, 0, 0));
+    }
+    let digits = match seconds_fraction.strip_prefix('.') {
+        Some(digits) => digits,
+        None => return Err(exception::parse_error("parse error in seconds fraction", time_str)),
+    };
+    if digits.is_empty() || !digits.bytes().all(|digit| digit.is_ascii_digit()) {
+        return Err(exception::parse_error("parse error in seconds fraction", time_str));
+    }
+    // digits beyond nano seconds are truncated
+    let mut nsec_str = String::from(&digits[..digits.len().min(9)]);
+    while nsec_str.len() < 9 {
+        nsec_str.push('0');
+    }
+    match nsec_str.parse::<i32>() {
+        Err(err) => Err(exception::parse_error("parse error in seconds fraction", time_str).with_source(err)),
+        Ok(nsec) => Ok((seconds_part, nsec, digits.len())),
+    }
+}
+
+///////////////
+// functions //
+///////////////
+
+// returns the ASCII time code A with the given number of fraction digits
+pub fn get_time_str_a(timespec: time::Timespec, fraction_digits: usize) -> String {
+    let tm = time::at_utc(timespec);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}Z",
+        tm.tm_year + 1900,
+        tm.tm_mon + 1,
+        tm.tm_mday,
+        tm.tm_hour,
+        tm.tm_min,
+        tm.tm_sec,
+        get_fraction_str(tm.tm_nsec, fraction_digits))
+}
+
+// returns the ASCII time code B with the given number of fraction digits
+pub fn get_time_str_b(timespec: time::Timespec, fraction_digits: usize) -> String {
+    let tm = time::at_utc(timespec);
+    format!(
+        "{:04}-{:03}T{:02}:{:02}:{:02}{}Z",
+        tm.tm_year + 1900,
+        tm.tm_yday + 1,
+        tm.tm_hour,
+        tm.tm_min,
+        tm.tm_sec,
+        get_fraction_str(tm.tm_nsec, fraction_digits))
+}
+
+// extracts a timespec from an ASCII time code A formated string
+pub fn parse_time_a(time_str: &str) ->
+    Result<time::Timespec, exception::Exception> {
+    let (seconds_part, nsec, _) = split_fraction(time_str, CODE_A_PATTERN)?;
+    let mut tm = match time::strptime(seconds_part, "%Y-%m-%dT%H:%M:%S") {
+        Err(err) => return Err(exception::parse_error("parse error in seconds part", time_str).with_source(err)),
+        Ok(tm) => tm
+    };
+    // strptime accepts days 29..31 in every month,
+    // to_timespec() would roll them over into the next month,
+    // the check ignores a leap second that rolls over as well
+    let mut check_tm = tm;
+    check_tm.tm_sec = cmp::min(check_tm.tm_sec, 59);
+    let check_tm = time::at_utc(check_tm.to_timespec());
+    if check_tm.tm_mday != tm.tm_mday {
+        let days_of_month = tm.tm_mday - check_tm.tm_mday;
+        return Err(exception::value_out_of_range("day of month out of range",
+            tm.tm_mday as i128, 1, days_of_month as i128));
+    }
+    // consider nano-seconds
+    tm.tm_nsec = nsec;
+    Ok(tm.to_timespec())
+}
+
+// extracts a timespec from an ASCII time code B formated string
+pub fn parse_time_b(time_str: &str) ->
+    Result<time::Timespec, exception::Exception> {
+    let (seconds_part, nsec, _) = split_fraction(time_str, CODE_B_PATTERN)?;
+    let mut tm = match time::strptime(seconds_part, "%Y-%jT%H:%M:%S") {
+        Err(err) => return Err(exception::parse_error("parse error in seconds part", time_str).with_source(err)),
+        Ok(tm) => tm
+    };
+    // mday and mon are 0 after the parse -->
+    // set it to 1st January
+    tm.tm_mday = 1;
+    // consider nano-seconds
+    tm.tm_nsec = nsec;
+    let mut timespec = tm.to_timespec();
+    // to_timespec() does not consider the yday --> do this now
+    let yday_compensation = tm.tm_yday * (24 * 60 * 60);
+    timespec.sec += yday_compensation as i64;
+    // day 366 of a non-leap year rolls over into the next year,
+    // the check ignores a leap second that rolls over as well
+    let leap_second = (tm.tm_sec - cmp::min(tm.tm_sec, 59)) as i64;
+    if time::at_utc(time::Timespec::new(timespec.sec - leap_second, 0)).tm_year != tm.tm_year {
+        return Err(exception::value_out_of_range("day of year out of range",
+            (tm.tm_yday + 1) as i128, 1, 365));
+    }
+    Ok(timespec)
+}
+
+// extracts a timespec from an ASCII time code A or B formated string,
+// the code is identified by the position of the 'T'
+pub fn parse_time(time_str: &str) ->
+    Result<time::Timespec, exception::Exception> {
+    match time_str.find('T') {
+        Some(10) => parse_time_a(time_str),
+        Some(8) => parse_time_b(time_str),
+        _ => Err(exception::parse_error("parse error: no ASCII time code A or B", time_str)),
+    }
+}
+
+// converts an ASCII time code A or B into the ASD time format,
+// the precision of the seconds fraction is kept
+pub fn get_asd_time_str(time_str: &str) ->
+    Result<String, exception::Exception> {
+    let timespec = parse_time(time_str)?;
+    let pattern = if time_str.find('T') == Some(10) {CODE_A_PATTERN} else {CODE_B_PATTERN};
+    let (_, _, fraction_digits) = split_fraction(time_str, pattern)?;
+    Ok(asd_time::get_time_str_with_precision(timespec, fraction_digits))
+}
@@ -12,12 +12,11 @@
 //*****************************************************************************
 // CCSDS Stack - CCSDS Packet Module                                          *
 //*****************************************************************************
+use ccsds::cds_time;
 use ccsds::cuc_time;
-use std::ops;
-use std::u32;
+use std::fmt;
 use util::crc;
 use util::du;
-use util::du::DUintf;
 use util::exception;
 
 ///////////////
@@ -31,6 +30,7 @@ pub const SEGMENTATION_FIRST: u32 = 1;
 pub const SEGMENTATION_LAST: u32 = 2;
 pub const SEGMENTATION_NONE: u32 = 3;
 pub const CRC_BYTE_SIZE: usize = 2;
+pub const CRC32_BYTE_SIZE: usize = 4;
 pub const PRIMARY_HEADER_BYTE_SIZE: usize = 6;
 pub const N_BYTE_SIZE: usize = 4;
 pub const TM_N_BYTE_SIZE: usize = 4;
@@ -47,6 +47,119 @@ pub mod primary_header {
     def_unsigned_accessor!(PACKET_LENGTH,      4,  2);
 }
 
+///////////////////
+// error control //
+///////////////////
+
+// type of the packet error control field at the end of the packet
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ErrorControl {
+    // CRC-16 (CCITT), the default
+    Crc16,
+    // ISO 8473 checksum, alternative in PUS
+    IsoChecksum,
+    // CRC-32 IEEE 802.3
+    Crc32Ieee,
+    // CRC-32 CCSDS (not reflected, no final XOR)
+    Crc32Ccsds
+}
+
+impl ErrorControl {
+    // size of the error control field
+    pub fn byte_size(&self) -> usize {
+        match *self {
+            ErrorControl::Crc16 | ErrorControl::IsoChecksum => CRC_BYTE_SIZE,
+            ErrorControl::Crc32Ieee | ErrorControl::Crc32Ccsds => CRC32_BYTE_SIZE
+        }
+    }
+    // calculates the error control value from the bytes
+    pub fn calculate(&self, bytes: &[u8]) -> u32 {
+        match *self {
+            ErrorControl::Crc16 => calculate_checksum(&[bytes]) as u32,
+            ErrorControl::IsoChecksum => crc::calculate_iso(bytes, bytes.len()) as u32,
+            ErrorControl::Crc32Ieee => crc::calculate32_ieee(bytes, bytes.len()),
+            ErrorControl::Crc32Ccsds => crc::calculate32_ccsds(bytes, bytes.len())
+        }
+    }
+}
+
+////////////////
+// validation //
+////////////////
+
+// checks that are done by PacketIntf::validate_with()
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ValidationOptions {
+    pub error_control: ErrorControl,
+    // only SEGMENTATION_NONE is accepted
+    pub require_unsegmented: bool
+}
+
+impl Default for ValidationOptions {
+    fn default() -> ValidationOptions {
+        ValidationOptions {
+            error_control: ErrorControl::Crc16,
+            require_unsegmented: false
+        }
+    }
+}
+
+// reason for rejecting a packet
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidationIssue {
+    // the primary header is not complete, no further checks possible
+    IncompleteHeader {size: usize},
+    InvalidVersionNumber {version_number: u32},
+    InvalidSegmentationFlags {segmentation_flags: u32},
+    // packet size derived from the packet length field vs buffer size
+    LengthMismatch {expected_size: usize, actual_size: usize},
+    // no space for the error control field after the primary header
+    MissingErrorControl {size: usize, error_control: ErrorControl},
+    // checksum in the packet vs checksum computed from the packet
+    ChecksumMismatch {expected: u32, computed: u32}
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ValidationIssue::IncompleteHeader {size} =>
+                write!(f, "incomplete packet header, size {}", size),
+            ValidationIssue::InvalidVersionNumber {version_number} =>
+                write!(f, "invalid version number {}", version_number),
+            ValidationIssue::InvalidSegmentationFlags {segmentation_flags} =>
+                write!(f, "invalid segmentation flags {}", segmentation_flags),
+            ValidationIssue::LengthMismatch {expected_size, actual_size} =>
+                write!(f, "packet length field gives size {}, actual size is {}", expected_size, actual_size),
+            ValidationIssue::MissingErrorControl {size, error_control} =>
+                write!(f, "packet size {} too small for {:?}", size, error_control),
+            ValidationIssue::ChecksumMismatch {expected, computed} =>
+                write!(f, "checksum in packet {:x}, computed checksum {:x}", expected, computed)
+        }
+    }
+}
+
+// result of PacketIntf::validate(), empty if the packet is valid
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_valid() {
+            return write!(f, "valid packet");
+        }
+        let issues: Vec<String> = self.issues.iter().map(|issue| issue.to_string()).collect();
+        write!(f, "{}", issues.join("; "))
+    }
+}
+
 ///////////////////////////////////
 // accessors for different types //
 ///////////////////////////////////
@@ -63,6 +176,121 @@ macro_rules! def_cuc_time_accessor {
     };
 }
 
+#[derive(Copy, Clone, Debug)]
+pub struct CdsTimeAccessor {
+    pub byte_pos: usize,
+    pub p_field: u8
+}
+#[macro_export]
+macro_rules! def_cds_time_accessor {
+    ($acc_name: ident, $byte_pos: expr, $p_field: expr) => {
+        pub const $acc_name: c_packet::CdsTimeAccessor = c_packet::CdsTimeAccessor {byte_pos: $byte_pos, p_field: $p_field};
+    };
+}
+
+///////////////
+// functions //
+///////////////
+
+// calculates the packet checksum over bytes in several buffers,
+// e.g. segments of a packet that is not yet reassembled
+pub fn calculate_checksum(segments: &[&[u8]]) -> u16 {
+    let mut crc = crc::Crc16::new();
+    for segment in segments {
+        crc.update(segment);
+    }
+    crc.finalize()
+}
+
+// CUC time access on any data unit, used by PacketIntf and derived data units
+pub fn get_cuc_time<T: du::DUintf + ?Sized>(du: &T, byte_pos: usize, p_field: u8) ->
+    Result<cuc_time::Time, exception::Exception> {
+    match cuc_time::Format::new_from_p_field(p_field) {
+        Err(err) => Err(err),
+        Ok(format) => get_cuc_time_with_format(du, byte_pos, &format),
+    }
+}
+pub fn set_cuc_time<T: du::DUintf + ?Sized>(du: &mut T, byte_pos: usize, p_field: u8, cuc_time: cuc_time::Time) ->
+    Result<(), exception::Exception> {
+    match cuc_time::Format::new_from_p_field(p_field) {
+        Err(err) => Err(err),
+        Ok(format) => set_cuc_time_with_format(du, byte_pos, &format, cuc_time),
+    }
+}
+// CUC time access for any format, incl. p-field extension
+pub fn get_cuc_time_with_format<T: du::DUintf + ?Sized>(du: &T, byte_pos: usize, format: &cuc_time::Format) ->
+    Result<cuc_time::Time, exception::Exception> {
+    // consistency checks
+    let data_size = format.get_full_data_size();
+    if (byte_pos + data_size) > du.size() {
+        return Err(exception::out_of_bounds("byte_pos/data_size out of buffer", byte_pos, data_size, du.size()));
+    };
+    let bytes = &du.buffer_read_only()[byte_pos..];
+    if format.embedded_p_field &&
+       (cuc_time::Format::new_from_bytes(bytes).ok() != Some(*format)) {
+        return Err(exception::invalid_p_field("unexpected p-field in buffer", bytes[0]));
+    };
+    // create the correct variant of cuc_time
+    let mut cuc_time = cuc_time::Time::new_from_format(*format);
+    // copy the exact amount of bytes from the data unit into cuc_time
+    cuc_time.init_from_bytes(bytes);
+    Ok(cuc_time)
+}
+pub fn set_cuc_time_with_format<T: du::DUintf + ?Sized>(du: &mut T, byte_pos: usize, format: &cuc_time::Format, cuc_time: cuc_time::Time) ->
+    Result<(), exception::Exception> {
+    // consistency checks
+    if cuc_time.get_format() != *format {
+        return Err(exception::invalid_p_field("CUC time does not match the p-field of the accessor", cuc_time.get_p_field()));
+    };
+    let data_size = format.get_full_data_size();
+    if (byte_pos + data_size) > du.size() {
+        return Err(exception::out_of_bounds("byte_pos/data_size out of buffer", byte_pos, data_size, du.size()));
+    };
+    // copy the exact amount of bytes from cuc_time into the data unit
+    cuc_time.update_to_bytes(&mut du.buffer_read_write()[byte_pos..]);
+    Ok(())
+}
+// CUC time access with the format taken from the embedded p-field
+pub fn get_embedded_cuc_time<T: du::DUintf + ?Sized>(du: &T, byte_pos: usize) ->
+    Result<cuc_time::Time, exception::Exception> {
+    if byte_pos > du.size() {
+        return Err(exception::out_of_bounds("byte_pos out of buffer", byte_pos, 0, du.size()));
+    };
+    cuc_time::Time::new_from_bytes(&du.buffer_read_only()[byte_pos..])
+}
+
+// CDS time access on any data unit, used by PacketIntf and derived data units
+pub fn get_cds_time<T: du::DUintf + ?Sized>(du: &T, byte_pos: usize, p_field: u8) ->
+    Result<cds_time::Time, exception::Exception> {
+    // consistency checks
+    let data_size = cds_time::get_full_data_size(p_field)?;
+    if (byte_pos + data_size) > du.size() {
+        return Err(exception::out_of_bounds("byte_pos/data_size out of buffer", byte_pos, data_size, du.size()));
+    };
+    if cds_time::has_p_field(p_field) &&
+       (p_field != du.buffer_read_only()[byte_pos]) {
+        return Err(exception::invalid_p_field("unexpected p-field in buffer", du.buffer_read_only()[byte_pos]));
+    };
+    // copy the exact amount of bytes from the data unit into cds_time
+    let mut cds_time = cds_time::Time::new_from_p_field(p_field)?;
+    cds_time.init_from_bytes(&du.buffer_read_only()[byte_pos..])?;
+    Ok(cds_time)
+}
+pub fn set_cds_time<T: du::DUintf + ?Sized>(du: &mut T, byte_pos: usize, p_field: u8, cds_time: cds_time::Time) ->
+    Result<(), exception::Exception> {
+    // consistency checks
+    let data_size = cds_time::get_full_data_size(p_field)?;
+    if (byte_pos + data_size) > du.size() {
+        return Err(exception::out_of_bounds("byte_pos/data_size out of buffer", byte_pos, data_size, du.size()));
+    };
+    if cds_time.get_p_field() != p_field {
+        return Err(exception::invalid_p_field("CDS time does not match the p-field", cds_time.get_p_field()));
+    };
+    // copy the exact amount of bytes from cds_time into the data unit
+    cds_time.update_to_bytes(&mut du.buffer_read_write()[byte_pos..]);
+    Ok(())
+}
+
 //########################
 // Packet...CCSDS Packet #
 //########################
@@ -140,40 +368,11 @@ pub trait PacketIntf: du::DUintf {
     // CUC time  access
     fn get_cuc_time(&self, byte_pos: usize, p_field: u8) ->
         Result<cuc_time::Time, exception::Exception> {
-        // consistency checks
-        let data_size = match cuc_time::get_full_data_size(p_field) {
-            Err(err) => return Err(err),
-            Ok(data_size) => data_size,
-        };
-        if (byte_pos + data_size) > self.size() {
-            return Err(exception::raise("byte_pos/data_size out of buffer"));
-        };
-        if cuc_time::has_p_field(p_field) &&
-           (p_field != self.buffer_read_only()[byte_pos]) {
-            return Err(exception::raise("unexpected p-field in buffer"));
-        };
-        // create the correct variant of cuc_time
-        let mut cuc_time = match cuc_time::Time::new_from_p_field(p_field) {
-            Err(err) => return Err(err),
-            Ok(cuc_time) => cuc_time,
-        };
-        // copy the exact amount of bytes from cuc_time into self
-        cuc_time.init_from_bytes(&self.buffer_read_only()[byte_pos..]);
-        Ok(cuc_time)
+        get_cuc_time(self, byte_pos, p_field)
     }
     fn set_cuc_time(&mut self, byte_pos: usize, p_field: u8, cuc_time: cuc_time::Time) ->
         Result<(), exception::Exception> {
-        // consistency checks
-        let data_size = match cuc_time::get_full_data_size(p_field) {
-            Err(err) => return Err(err),
-            Ok(data_size) => data_size,
-        };
-        if (byte_pos + data_size) > self.size() {
-            return Err(exception::raise("byte_pos/data_size out of buffer"));
-        };
-        // copy the exact amount of bytes from cuc_time into self
-        cuc_time.update_to_bytes(&mut self.buffer_read_write()[byte_pos..]);
-        Ok(())
+        set_cuc_time(self, byte_pos, p_field, cuc_time)
     }
     fn get_cuc_time_acc(&self, acc: CucTimeAccessor) ->
         Result<cuc_time::Time, exception::Exception> {
@@ -183,6 +382,23 @@ pub trait PacketIntf: du::DUintf {
         Result<(), exception::Exception> {
         self.set_cuc_time(acc.byte_pos, acc.p_field, cuc_time)
     }
+    // CDS time access
+    fn get_cds_time(&self, byte_pos: usize, p_field: u8) ->
+        Result<cds_time::Time, exception::Exception> {
+        get_cds_time(self, byte_pos, p_field)
+    }
+    fn set_cds_time(&mut self, byte_pos: usize, p_field: u8, cds_time: cds_time::Time) ->
+        Result<(), exception::Exception> {
+        set_cds_time(self, byte_pos, p_field, cds_time)
+    }
+    fn get_cds_time_acc(&self, acc: CdsTimeAccessor) ->
+        Result<cds_time::Time, exception::Exception> {
+        self.get_cds_time(acc.byte_pos, acc.p_field)
+    }
+    fn set_cds_time_acc(&mut self, acc: CdsTimeAccessor, cds_time: cds_time::Time) ->
+        Result<(), exception::Exception> {
+        self.set_cds_time(acc.byte_pos, acc.p_field, cds_time)
+    }
 
     ///////////////////
     // other methods //
@@ -191,81 +407,199 @@ pub trait PacketIntf: du::DUintf {
     // sets the packetLength according to the data unit's buffer size
     fn set_packet_length(&mut self) ->
         Result<(), exception::Exception> {
+        // the packet length field has 16 bits
+        let max_size = 0xFFFF + PRIMARY_HEADER_BYTE_SIZE + 1;
         if self.size() < (PRIMARY_HEADER_BYTE_SIZE + 1) {
-            return Err(exception::raise("packet size is too small"));
+            return Err(exception::value_out_of_range("packet size is too small",
+                self.size() as i128, (PRIMARY_HEADER_BYTE_SIZE + 1) as i128, max_size as i128));
         }
-        let length_value = self.size() - PRIMARY_HEADER_BYTE_SIZE - 1;
-        if length_value > (u32::MAX as usize) {
-            return Err(exception::raise("packet size is too large"));
+        if self.size() > max_size {
+            return Err(exception::value_out_of_range("packet size is too large",
+                self.size() as i128, (PRIMARY_HEADER_BYTE_SIZE + 1) as i128, max_size as i128));
         }
+        let length_value = self.size() - PRIMARY_HEADER_BYTE_SIZE - 1;
         self.set_packet_length_field(length_value as u32)
     }
     // checks the packetLength according to the data unit's buffer size
     fn check_packet_length(&self) ->
         Result<(bool), exception::Exception> {
-        Ok((self.get_packet_length_field()? as usize) ==
-           (self.size() - PRIMARY_HEADER_BYTE_SIZE - 1))
+        let packet_length = self.get_packet_length_field()? as usize;
+        Ok(self.size() > PRIMARY_HEADER_BYTE_SIZE &&
+           packet_length == (self.size() - PRIMARY_HEADER_BYTE_SIZE - 1))
     }
     // sets the checksum out of the binary data,
     // buffer and packetLength must be correctly initialised
     fn set_checksum(&mut self) ->
         Result<(), exception::Exception> {
-        if !self.check_packet_length()? {
-            return Err(exception::raise("inconsistent packet length"));
+        self.set_checksum_with(ErrorControl::Crc16)
+    }
+    // sets the checksum of a packet that is not yet reassembled,
+    // this data unit is the last segment and contains the checksum,
+    // the previous segments start with the primary header
+    fn set_checksum_segmented(&mut self, previous_segments: &[&[u8]]) ->
+        Result<(), exception::Exception> {
+        if self.size() < 2 {
+            return Err(exception::out_of_bounds("segment too small for checksum",
+                0, 2, self.size()));
         }
-        let crc_pos = self.size() - CRC_BYTE_SIZE;
-        let crc = crc::calculate16(self.buffer_read_only(), crc_pos);
-        self.set_unsigned(crc_pos, CRC_BYTE_SIZE, crc as u32)
+        let crc_pos = self.size() - 2;
+        let mut segments = previous_segments.to_vec();
+        segments.push(&self.buffer_read_only()[..crc_pos]);
+        let crc = calculate_checksum(&segments);
+        self.set_unsigned(crc_pos, 2, crc as u32)
     }
     // checks the checksum out of the binary data,
     // buffer and, packetLength must be correctly initialised
     fn check_checksum(&self) ->
         Result<(bool), exception::Exception> {
+        self.check_checksum_with(ErrorControl::Crc16)
+    }
+    // sets the error control field with the selected type,
+    // buffer and packetLength must be correctly initialised
+    fn set_checksum_with(&mut self, error_control: ErrorControl) ->
+        Result<(), exception::Exception> {
+        if !self.check_packet_length()? {
+            let expected_size = self.get_packet_length_field()? as usize + PRIMARY_HEADER_BYTE_SIZE + 1;
+            return Err(exception::length_mismatch("inconsistent packet length",
+                expected_size, self.size()));
+        }
+        let byte_size = error_control.byte_size();
+        if self.size() < PRIMARY_HEADER_BYTE_SIZE + byte_size {
+            return Err(exception::out_of_bounds("packet too small for error control",
+                PRIMARY_HEADER_BYTE_SIZE, byte_size, self.size()));
+        }
+        let crc_pos = self.size() - byte_size;
+        let crc = error_control.calculate(&self.buffer_read_only()[..crc_pos]);
+        self.set_unsigned(crc_pos, byte_size, crc)
+    }
+    // checks the error control field with the selected type and
+    // reports the reason of a failed check as error
+    fn verify_checksum_with(&self, error_control: ErrorControl) ->
+        Result<(), exception::Exception> {
         if !self.check_packet_length()? {
+            let expected_size = self.get_packet_length_field()? as usize + PRIMARY_HEADER_BYTE_SIZE + 1;
+            return Err(exception::length_mismatch("inconsistent packet length",
+                expected_size, self.size()));
+        }
+        let byte_size = error_control.byte_size();
+        if self.size() < PRIMARY_HEADER_BYTE_SIZE + byte_size {
+            return Err(exception::out_of_bounds("packet too small for error control",
+                PRIMARY_HEADER_BYTE_SIZE, byte_size, self.size()));
+        }
+        let crc_pos = self.size() - byte_size;
+        let crc = error_control.calculate(&self.buffer_read_only()[..crc_pos]);
+        let expected = self.get_unsigned(crc_pos, byte_size)?;
+        if expected != crc {
+            return Err(exception::checksum_mismatch("checksum mismatch", expected, crc));
+        }
+        Ok(())
+    }
+    // checks the error control field with the selected type,
+    // buffer and, packetLength must be correctly initialised
+    fn check_checksum_with(&self, error_control: ErrorControl) ->
+        Result<bool, exception::Exception> {
+        let byte_size = error_control.byte_size();
+        if !self.check_packet_length()? || self.size() < PRIMARY_HEADER_BYTE_SIZE + byte_size {
             return Ok(false);
         }
-        let crc_pos = self.size() - CRC_BYTE_SIZE;
-        let crc = crc::calculate16(self.buffer_read_only(), crc_pos);
-        Ok(self.get_unsigned(crc_pos, CRC_BYTE_SIZE)? == (crc as u32))
+        let crc_pos = self.size() - byte_size;
+        let crc = error_control.calculate(&self.buffer_read_only()[..crc_pos]);
+        Ok(self.get_unsigned(crc_pos, byte_size)? == crc)
+    }
+
+    ////////////////////////
+    // validation methods //
+    ////////////////////////
+
+    // checks the primary header and the CRC-16
+    fn validate(&self) -> ValidationReport {
+        self.validate_with(&ValidationOptions::default())
+    }
+    // checks the primary header and the error control, all issues
+    // are reported, e.g. for logging of rejected packets
+    fn validate_with(&self, options: &ValidationOptions) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        let size = self.size();
+        if size < PRIMARY_HEADER_BYTE_SIZE {
+            report.issues.push(ValidationIssue::IncompleteHeader {size});
+            return report;
+        }
+        // the primary header is complete, the fields can be read
+        let version_number = self.get_version_number_field().unwrap();
+        if version_number != VERSION_NUMBER {
+            report.issues.push(ValidationIssue::InvalidVersionNumber {version_number});
+        }
+        let segmentation_flags = self.get_segmentation_flags_field().unwrap();
+        if options.require_unsegmented && segmentation_flags != SEGMENTATION_NONE {
+            report.issues.push(ValidationIssue::InvalidSegmentationFlags {segmentation_flags});
+        }
+        let expected_size = self.get_packet_length_field().unwrap() as usize + PRIMARY_HEADER_BYTE_SIZE + 1;
+        if expected_size != size {
+            report.issues.push(ValidationIssue::LengthMismatch {expected_size, actual_size: size});
+        }
+        let error_control = options.error_control;
+        let byte_size = error_control.byte_size();
+        if size < PRIMARY_HEADER_BYTE_SIZE + byte_size {
+            report.issues.push(ValidationIssue::MissingErrorControl {size, error_control});
+            return report;
+        }
+        let crc_pos = size - byte_size;
+        let expected = self.get_unsigned(crc_pos, byte_size).unwrap();
+        let computed = error_control.calculate(&self.buffer_read_only()[..crc_pos]);
+        if expected != computed {
+            report.issues.push(ValidationIssue::ChecksumMismatch {expected, computed});
+        }
+        report
+    }
+
+    //////////////////
+    // dump methods //
+    //////////////////
+
+    // dumps the decoded primary header fields followed by the buffer
+    fn dump_annotated_str(&self) -> String {
+        if self.size() < (PRIMARY_HEADER_BYTE_SIZE + 1) {
+            return format!("\nincomplete packet header{}", self.dump_str());
+        }
+        let mut ret_val = String::new();
+        let packet_type = self.get_packet_type_field().unwrap();
+        let packet_type_str = if packet_type == TC_PACKET_TYPE {"TC"} else {"TM"};
+        let segmentation_flags = self.get_segmentation_flags_field().unwrap();
+        let segmentation_flags_str = match segmentation_flags {
+            SEGMENTATION_CONTINUATION => "continuation",
+            SEGMENTATION_FIRST => "first",
+            SEGMENTATION_LAST => "last",
+            _ => "none"
+        };
+        let length_str = if self.check_packet_length().unwrap() {"consistent"} else {"inconsistent"};
+        let checksum_str = if self.check_checksum().unwrap() {"ok"} else {"invalid"};
+        ret_val += &format!("\nversion number         = {}", self.get_version_number_field().unwrap());
+        ret_val += &format!("\npacket type            = {} ({})", packet_type, packet_type_str);
+        ret_val += &format!("\ndata field header flag = {}", self.get_data_field_header_flag_field().unwrap());
+        ret_val += &format!("\napplication process id = {}", self.get_application_process_id_field().unwrap());
+        ret_val += &format!("\nsegmentation flags     = {} ({})", segmentation_flags, segmentation_flags_str);
+        ret_val += &format!("\nsequence control count = {}", self.get_sequence_control_count_field().unwrap());
+        ret_val += &format!("\npacket length          = {} ({})", self.get_packet_length_field().unwrap(), length_str);
+        ret_val += &format!("\nchecksum               = {}", checksum_str);
+        ret_val += &self.dump_str();
+        ret_val
+    }
+    // dumps the decoded primary header fields and the buffer to standard out
+    fn dump_annotated(&self, prefix: &str) {
+        println!("{} = {}", prefix, self.dump_annotated_str());
     }
 }
 
 ///////////////////////////
 // implementation struct //
 ///////////////////////////
+#[derive(DataUnit)]
+#[data_unit(no_constructors)]
 pub struct Packet<'a> {
     buffer: du::HybridVector<'a>
 }
 
 // trait implementations
-impl<'a> ops::Index<usize> for Packet<'a> {
-    type Output = u8;
-    fn index(&self, pos: usize) -> &u8 {
-        self.at(pos)
-    }
-}
-
-impl<'a> ops::IndexMut<usize> for Packet<'a> {
-    fn index_mut(&mut self, pos: usize) -> &mut u8 {
-        self.at_mut(pos)
-    }
-}
-
-impl<'a> du::DUintf for Packet<'a> {
-    // returns a read-only reference
-    fn buffer_read_only(&self) -> &[u8] {
-        self.buffer.read_only()
-    }
-    // returns a read-write reference 
-    fn buffer_read_write(&mut self) -> &mut [u8] {
-        self.buffer.read_write()
-    }
-    // change size
-    fn resize(&mut self, new_size: usize) {
-        self.buffer.resize(new_size);
-    }
-}
-
 impl<'a> PacketIntf for Packet<'a> {
 }
 
@@ -324,39 +658,13 @@ impl<'a> Packet<'a> {
 ///////////////////////////
 // implementation struct //
 ///////////////////////////
+#[derive(DataUnit)]
+#[data_unit(no_constructors)]
 pub struct TMpacket<'a> {
     buffer: du::HybridVector<'a>
 }
 
 // trait implementations
-impl<'a> ops::Index<usize> for TMpacket<'a> {
-    type Output = u8;
-    fn index(&self, pos: usize) -> &u8 {
-        self.at(pos)
-    }
-}
-
-impl<'a> ops::IndexMut<usize> for TMpacket<'a> {
-    fn index_mut(&mut self, pos: usize) -> &mut u8 {
-        self.at_mut(pos)
-    }
-}
-
-impl<'a> du::DUintf for TMpacket<'a> {
-    // returns a read-only reference
-    fn buffer_read_only(&self) -> &[u8] {
-        self.buffer.read_only()
-    }
-    // returns a read-write reference 
-    fn buffer_read_write(&mut self) -> &mut [u8] {
-        self.buffer.read_write()
-    }
-    // change size
-    fn resize(&mut self, new_size: usize) {
-        self.buffer.resize(new_size);
-    }
-}
-
 impl<'a> PacketIntf for TMpacket<'a> {
 }
 
@@ -415,39 +723,13 @@ impl<'a> TMpacket<'a> {
 ///////////////////////////
 // implementation struct //
 ///////////////////////////
+#[derive(DataUnit)]
+#[data_unit(no_constructors)]
 pub struct TCpacket<'a> {
     buffer: du::HybridVector<'a>
 }
 
 // trait implementations
-impl<'a> ops::Index<usize> for TCpacket<'a> {
-    type Output = u8;
-    fn index(&self, pos: usize) -> &u8 {
-        self.at(pos)
-    }
-}
-
-impl<'a> ops::IndexMut<usize> for TCpacket<'a> {
-    fn index_mut(&mut self, pos: usize) -> &mut u8 {
-        self.at_mut(pos)
-    }
-}
-
-impl<'a> du::DUintf for TCpacket<'a> {
-    // returns a read-only reference
-    fn buffer_read_only(&self) -> &[u8] {
-        self.buffer.read_only()
-    }
-    // returns a read-write reference 
-    fn buffer_read_write(&mut self) -> &mut [u8] {
-        self.buffer.read_write()
-    }
-    // change size
-    fn resize(&mut self, new_size: usize) {
-        self.buffer.resize(new_size);
-    }
-}
-
 impl<'a> PacketIntf for TCpacket<'a> {
 }
 
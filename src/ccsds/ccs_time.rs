@@ -0,0 +1,363 @@
+//*****************************************************************************
+// (C) 2018, Stefan Korner, Austria                                           *
+//                                                                            *
+// The Space Rust Library is free software; you can redistribute it and/or    *
+// modify it under the terms of the MIT License as published by the           *
+// Massachusetts Institute of Technology.                                     *
+//                                                                            *
+// The Space Rust Library is distributed in the hope that it will be useful,  *
+// but WITHOUT ANY WARRANTY; without even the implied warranty of             *
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the MIT License   *
+// for more details.                                                          *
+//*****************************************************************************
+// CCS TIME - CCSDS calendar segmented time code                              *
+//                                                                            *
+// All fields are BCD encoded: YYYY MM DD hh mm ss (month/day of month)       *
+//                         or: YYYY DDD hh mm ss (day of year)                *
+// followed by 0 to 6 sub-second octets with 2 decimal digits each.           *
+// Conversions are from/to time::Timespec (from Crate time), UTC is assumed.  *
+//*****************************************************************************
+use std::fmt;
+use time;
+use util::asd_time;
+use util::exception;
+
+///////////////
+// constants //
+///////////////
+// supported time codes, the number of sub-second octets (resolution 0...6)
+// must be added to the p-field
+pub const L_MONTH_DAY: u8 = 0x50;   // embedded p-field, month/day of month
+pub const L_DAY_OF_YEAR: u8 = 0x58; // embedded p-field, day of year
+pub const T_MONTH_DAY: u8 = 0xd0;   // no embedded p-field, month/day of month
+pub const T_DAY_OF_YEAR: u8 = 0xd8; // no embedded p-field, day of year
+pub const MAX_RESOLUTION: u8 = 6;
+// for internal use
+const P_FIELD_NO_EMBEDDING: u8 = 0x80;
+const P_FIELD_TIME_CODE_MASK: u8 = 0x70;
+const P_FIELD_TIME_CODE_CCS: u8 = 0x50;
+const P_FIELD_DAY_OF_YEAR: u8 = 0x08;
+const P_FIELD_RESOLUTION_MASK: u8 = 0x07;
+const SEC_PER_DAY: i64 = 86400;
+
+//////////////////////
+// helper functions //
+//////////////////////
+
+// checks the time code id and the resolution
+fn check_p_field(p_field: u8) ->
+    Result<(), exception::Exception> {
+    if ((p_field & P_FIELD_TIME_CODE_MASK) != P_FIELD_TIME_CODE_CCS) ||
+       ((p_field & P_FIELD_RESOLUTION_MASK) > MAX_RESOLUTION) {
+        return Err(exception::invalid_p_field("invalid p-field for CCS time", p_field));
+    }
+    Ok(())
+}
+// tells if a time format has an explicit p-field
+pub fn has_p_field(p_field: u8) -> bool {
+    check_p_field(p_field).is_ok() && ((p_field & P_FIELD_NO_EMBEDDING) == 0)
+}
+// tells if a time format uses the day of year instead of month/day
+pub fn has_day_of_year(p_field: u8) -> bool {
+    (p_field & P_FIELD_DAY_OF_YEAR) != 0
+}
+// number of sub-second octets
+pub fn get_resolution(p_field: u8) -> usize {
+    (p_field & P_FIELD_RESOLUTION_MASK) as usize
+}
+// netto data size without embedded p-field
+pub fn get_data_size(p_field: u8) ->
+    Result<usize, exception::Exception> {
+    check_p_field(p_field)?;
+    Ok(7 + get_resolution(p_field))
+}
+// brutto data size with embedded p-field (if embedded p-field)
+pub fn get_full_data_size(p_field: u8) ->
+    Result<usize, exception::Exception> {
+    let data_size = get_data_size(p_field)?;
+    if has_p_field(p_field) {
+        Ok(data_size + 1)
+    } else {
+        Ok(data_size)
+    }
+}
+// decodes BCD octets into a decimal value
+fn decode_bcd(bytes: &[u8]) ->
+    Result<u64, exception::Exception> {
+    let mut value = 0_u64;
+    for byte in bytes {
+        let high = (byte >> 4) as u64;
+        let low = (byte & 0x0F) as u64;
+        if (high > 9) || (low > 9) {
+            return Err(exception::parse_error("invalid BCD octet in CCS time",
+                &format!("{:02x}", byte)));
+        }
+        value = (value * 100) + (high * 10) + low;
+    }
+    Ok(value)
+}
+// encodes a decimal value into BCD octets
+fn encode_bcd(bytes: &mut [u8], value: u64) {
+    let mut value = value;
+    for byte in bytes.iter_mut().rev() {
+        let digits = value % 100;
+        *byte = (((digits / 10) << 4) | (digits % 10)) as u8;
+        value /= 100;
+    }
+}
+// checks a calendar field
+fn check_field(description: &str, value: u64, min: u64, max: u64) ->
+    Result<(), exception::Exception> {
+    if (value < min) || (value > max) {
+        return Err(exception::value_out_of_range(description, value as i128, min as i128, max as i128));
+    }
+    Ok(())
+}
+
+///////////////////////
+// struct definition //
+///////////////////////
+#[derive(Copy, Clone, Debug)]
+pub struct Time {
+    p_field: u8,
+    year: u16,
+    month: u8,
+    day_of_month: u8,
+    day_of_year: u16,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    sub_second: u64,
+}
+
+////////////////////////////
+// methods implementation //
+////////////////////////////
+impl Time {
+    // initialization from p_field, the time is 1.1.1970
+    pub fn new_from_p_field(p_field: u8) ->
+        Result<Time, exception::Exception> {
+        check_p_field(p_field)?;
+        Ok(Time {
+            p_field,
+            year: 1970,
+            month: 1,
+            day_of_month: 1,
+            day_of_year: 1,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            sub_second: 0,
+        })
+    }
+    // initialization from p_field and timespec
+    pub fn new_from_timespec(p_field: u8, timespec: time::Timespec) ->
+        Result<Time, exception::Exception> {
+        let mut time = Time::new_from_p_field(p_field)?;
+        time.init_from_timespec(timespec)?;
+        Ok(time)
+    }
+    // initialization from p_field and ASD time string
+    pub fn new_from_asd_time_str(p_field: u8, time_str: &str) ->
+        Result<Time, exception::Exception> {
+        let timespec = asd_time::parse_time(time_str)?;
+        Time::new_from_timespec(p_field, timespec)
+    }
+    // initialization from bytes with embedded p-field
+    pub fn new_from_bytes(bytes: &[u8]) ->
+        Result<Time, exception::Exception> {
+        if bytes.is_empty() {
+            return Err(exception::out_of_bounds("p-field out of buffer", 0, 1, 0));
+        }
+        let p_field = bytes[0];
+        if !has_p_field(p_field) {
+            return Err(exception::invalid_p_field("invalid embedded p-field for CCS time", p_field));
+        }
+        let data_size = get_full_data_size(p_field)?;
+        if data_size > bytes.len() {
+            return Err(exception::out_of_bounds("CCS time out of buffer", 0, data_size, bytes.len()));
+        }
+        let mut time = Time::new_from_p_field(p_field)?;
+        time.init_from_bytes(bytes)?;
+        Ok(time)
+    }
+    // accessors
+    pub fn get_p_field(self) -> u8 {
+        self.p_field
+    }
+    pub fn get_year(self) -> u16 {
+        self.year
+    }
+    pub fn get_month(self) -> u8 {
+        self.month
+    }
+    pub fn get_day_of_month(self) -> u8 {
+        self.day_of_month
+    }
+    pub fn get_day_of_year(self) -> u16 {
+        self.day_of_year
+    }
+    pub fn get_hour(self) -> u8 {
+        self.hour
+    }
+    pub fn get_minute(self) -> u8 {
+        self.minute
+    }
+    pub fn get_second(self) -> u8 {
+        self.second
+    }
+    // sub-seconds in units of the resolution, e.g. 1/100 s for 1 octet
+    pub fn get_sub_second(self) -> u64 {
+        self.sub_second
+    }
+    // initialization from timespec
+    pub fn init_from_timespec(&mut self, timespec: time::Timespec) ->
+        Result<(), exception::Exception> {
+        let tm = time::at_utc(timespec);
+        let year = (tm.tm_year + 1900) as i64;
+        if !(0..=9999).contains(&year) {
+            return Err(exception::value_out_of_range("CCS time supports only years 0...9999",
+                year as i128, 0, 9999));
+        }
+        self.year = year as u16;
+        self.month = (tm.tm_mon + 1) as u8;
+        self.day_of_month = tm.tm_mday as u8;
+        self.day_of_year = (tm.tm_yday + 1) as u16;
+        self.hour = tm.tm_hour as u8;
+        self.minute = tm.tm_min as u8;
+        self.second = tm.tm_sec as u8;
+        // 2 decimal digits per sub-second octet
+        let digits = 2 * get_resolution(self.p_field) as u32;
+        let nsec = tm.tm_nsec as u64;
+        self.sub_second = if digits <= 9 {
+            nsec / 10_u64.pow(9 - digits)
+        } else {
+            nsec * 10_u64.pow(digits - 9)
+        };
+        Ok(())
+    }
+    // conversion to timespec, the sub-seconds are truncated to nano seconds
+    pub fn to_timespec(&self) -> time::Timespec {
+        let mut tm = time::empty_tm();
+        tm.tm_year = (self.year as i32) - 1900;
+        tm.tm_mon = (self.month as i32) - 1;
+        tm.tm_mday = self.day_of_month as i32;
+        tm.tm_hour = self.hour as i32;
+        tm.tm_min = self.minute as i32;
+        tm.tm_sec = self.second as i32;
+        let digits = 2 * get_resolution(self.p_field) as u32;
+        tm.tm_nsec = if digits <= 9 {
+            (self.sub_second * 10_u64.pow(9 - digits)) as i32
+        } else {
+            (self.sub_second / 10_u64.pow(digits - 9)) as i32
+        };
+        tm.to_timespec()
+    }
+    // conversion to the ASD time format with the precision of the resolution
+    pub fn get_asd_time_str(&self) -> String {
+        asd_time::get_time_str_with_precision(self.to_timespec(), 2 * get_resolution(self.p_field))
+    }
+    // initialization from bytes, skips p-field (if embedded p-field)
+    pub fn init_from_bytes(&mut self, bytes: &[u8]) ->
+        Result<(), exception::Exception> {
+        let mut pos = if has_p_field(self.p_field) {1} else {0};
+        let year = decode_bcd(&bytes[pos..(pos + 2)])?;
+        let (month, day_of_month, day_of_year) = if has_day_of_year(self.p_field) {
+            let day_of_year = decode_bcd(&bytes[(pos + 2)..(pos + 4)])?;
+            check_field("CCS time day of year out of range", day_of_year, 1, 366)?;
+            // derive month and day of month from the day of year
+            let mut tm = time::empty_tm();
+            tm.tm_year = (year as i32) - 1900;
+            tm.tm_mday = 1;
+            let mut timespec = tm.to_timespec();
+            timespec.sec += ((day_of_year as i64) - 1) * SEC_PER_DAY;
+            let tm = time::at_utc(timespec);
+            if (tm.tm_year + 1900) as u64 != year {
+                // only day 366 of a non-leap year rolls over
+                return Err(exception::value_out_of_range("CCS time day of year out of range",
+                    day_of_year as i128, 1, 365));
+            }
+            ((tm.tm_mon + 1) as u64, tm.tm_mday as u64, day_of_year)
+        } else {
+            let month = decode_bcd(&bytes[(pos + 2)..(pos + 3)])?;
+            let day_of_month = decode_bcd(&bytes[(pos + 3)..(pos + 4)])?;
+            check_field("CCS time month out of range", month, 1, 12)?;
+            check_field("CCS time day of month out of range", day_of_month, 1, 31)?;
+            // derive the day of year from month and day of month
+            let mut tm = time::empty_tm();
+            tm.tm_year = (year as i32) - 1900;
+            tm.tm_mon = (month as i32) - 1;
+            tm.tm_mday = day_of_month as i32;
+            let tm = time::at_utc(tm.to_timespec());
+            if ((tm.tm_mon + 1) as u64 != month) || (tm.tm_mday as u64 != day_of_month) {
+                // the day of month rolls over into the next month
+                let days_of_month = day_of_month - (tm.tm_mday as u64);
+                return Err(exception::value_out_of_range("CCS time day of month out of range",
+                    day_of_month as i128, 1, days_of_month as i128));
+            }
+            (month, day_of_month, (tm.tm_yday + 1) as u64)
+        };
+        pos += 4;
+        let hour = decode_bcd(&bytes[pos..(pos + 1)])?;
+        let minute = decode_bcd(&bytes[(pos + 1)..(pos + 2)])?;
+        let second = decode_bcd(&bytes[(pos + 2)..(pos + 3)])?;
+        check_field("CCS time hour out of range", hour, 0, 23)?;
+        check_field("CCS time minute out of range", minute, 0, 59)?;
+        // second 60 is a leap second
+        check_field("CCS time second out of range", second, 0, 60)?;
+        pos += 3;
+        let sub_second = decode_bcd(&bytes[pos..(pos + get_resolution(self.p_field))])?;
+        self.year = year as u16;
+        self.month = month as u8;
+        self.day_of_month = day_of_month as u8;
+        self.day_of_year = day_of_year as u16;
+        self.hour = hour as u8;
+        self.minute = minute as u8;
+        self.second = second as u8;
+        self.sub_second = sub_second;
+        Ok(())
+    }
+    // update the contents to bytes incl. p-field (if embedded p-field)
+    pub fn update_to_bytes(&self, bytes: &mut [u8]) {
+        let mut pos = 0;
+        if has_p_field(self.p_field) {
+            bytes[0] = self.p_field;
+            pos = 1;
+        }
+        encode_bcd(&mut bytes[pos..(pos + 2)], self.year as u64);
+        if has_day_of_year(self.p_field) {
+            encode_bcd(&mut bytes[(pos + 2)..(pos + 4)], self.day_of_year as u64);
+        } else {
+            encode_bcd(&mut bytes[(pos + 2)..(pos + 3)], self.month as u64);
+            encode_bcd(&mut bytes[(pos + 3)..(pos + 4)], self.day_of_month as u64);
+        }
+        pos += 4;
+        encode_bcd(&mut bytes[pos..(pos + 1)], self.hour as u64);
+        encode_bcd(&mut bytes[(pos + 1)..(pos + 2)], self.minute as u64);
+        encode_bcd(&mut bytes[(pos + 2)..(pos + 3)], self.second as u64);
+        pos += 3;
+        encode_bcd(&mut bytes[pos..(pos + get_resolution(self.p_field))], self.sub_second);
+    }
+    // dumps the contents to a string
+    pub fn dump_str(&self) -> String {
+        let digits = 2 * get_resolution(self.p_field);
+        let sub_second = if digits == 0 {
+            String::new()
+        } else {
+            format!(".{:0width$}", self.sub_second, width = digits)
+        };
+        if has_day_of_year(self.p_field) {
+            format!("{:04}-{:03}T{:02}:{:02}:{:02}{}",
+                self.year, self.day_of_year, self.hour, self.minute, self.second, sub_second)
+        } else {
+            format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}",
+                self.year, self.month, self.day_of_month, self.hour, self.minute, self.second, sub_second)
+        }
+    }
+}
+// trait implementation
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.dump_str())
+    }
+}
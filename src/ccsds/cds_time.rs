@@ -0,0 +1,306 @@
+//*****************************************************************************
+// (C) 2018, Stefan Korner, Austria                                           *
+//                                                                            *
+// The Space Rust Library is free software; you can redistribute it and/or    *
+// modify it under the terms of the MIT License as published by the           *
+// Massachusetts Institute of Technology.                                     *
+//                                                                            *
+// The Space Rust Library is distributed in the hope that it will be useful,  *
+// but WITHOUT ANY WARRANTY; without even the implied warranty of             *
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the MIT License   *
+// for more details.                                                          *
+//*****************************************************************************
+// CDS TIME - CCSDS day segmented time code                                   *
+//                                                                            *
+// Correlation to an epoch must be done explicitly via use of util::tco       *
+//*****************************************************************************
+use std::fmt;
+use time;
+use util::exception;
+
+///////////////
+// constants //
+///////////////
+// supported time codes with embedded p-field
+pub const L1_TIME_2_4_0: u8 = 0x40; // epoch: 1.1.1958, 16 bit day
+pub const L1_TIME_2_4_2: u8 = 0x41; // epoch: 1.1.1958, 16 bit day, usec
+pub const L1_TIME_2_4_4: u8 = 0x42; // epoch: 1.1.1958, 16 bit day, psec
+pub const L1_TIME_3_4_0: u8 = 0x44; // epoch: 1.1.1958, 24 bit day
+pub const L1_TIME_3_4_2: u8 = 0x45; // epoch: 1.1.1958, 24 bit day, usec
+pub const L1_TIME_3_4_4: u8 = 0x46; // epoch: 1.1.1958, 24 bit day, psec
+pub const L2_TIME_2_4_0: u8 = 0x48; // epoch: agency-def., 16 bit day
+pub const L2_TIME_2_4_2: u8 = 0x49; // epoch: agency-def., 16 bit day, usec
+pub const L2_TIME_2_4_4: u8 = 0x4a; // epoch: agency-def., 16 bit day, psec
+pub const L2_TIME_3_4_0: u8 = 0x4c; // epoch: agency-def., 24 bit day
+pub const L2_TIME_3_4_2: u8 = 0x4d; // epoch: agency-def., 24 bit day, usec
+pub const L2_TIME_3_4_4: u8 = 0x4e; // epoch: agency-def., 24 bit day, psec
+// supported time codes without embedded p-field
+pub const T1_TIME_2_4_0: u8 = 0xc0; // epoch: 1.1.1958, 16 bit day
+pub const T1_TIME_2_4_2: u8 = 0xc1; // epoch: 1.1.1958, 16 bit day, usec
+pub const T1_TIME_2_4_4: u8 = 0xc2; // epoch: 1.1.1958, 16 bit day, psec
+pub const T1_TIME_3_4_0: u8 = 0xc4; // epoch: 1.1.1958, 24 bit day
+pub const T1_TIME_3_4_2: u8 = 0xc5; // epoch: 1.1.1958, 24 bit day, usec
+pub const T1_TIME_3_4_4: u8 = 0xc6; // epoch: 1.1.1958, 24 bit day, psec
+pub const T2_TIME_2_4_0: u8 = 0xc8; // epoch: agency-def., 16 bit day
+pub const T2_TIME_2_4_2: u8 = 0xc9; // epoch: agency-def., 16 bit day, usec
+pub const T2_TIME_2_4_4: u8 = 0xca; // epoch: agency-def., 16 bit day, psec
+pub const T2_TIME_3_4_0: u8 = 0xcc; // epoch: agency-def., 24 bit day
+pub const T2_TIME_3_4_2: u8 = 0xcd; // epoch: agency-def., 24 bit day, usec
+pub const T2_TIME_3_4_4: u8 = 0xce; // epoch: agency-def., 24 bit day, psec
+// for internal use
+const P_FIELD_NO_EMBEDDING: u8 = 0x80;
+const P_FIELD_TIME_CODE_MASK: u8 = 0x70;
+const P_FIELD_TIME_CODE_CDS: u8 = 0x40;
+const P_FIELD_EPOCH: u8 = 0x08;
+const P_FIELD_DAY_24_BIT: u8 = 0x04;
+const P_FIELD_SUB_MS_MASK: u8 = 0x03;
+const SEC_PER_DAY: i64 = 86400;
+const MSEC_PER_DAY: u32 = 86400000;
+// a leap second extends the last day segment by 1000 ms
+const MAX_MSEC_OF_DAY: u32 = MSEC_PER_DAY + 999;
+
+//////////////////////
+// helper functions //
+//////////////////////
+
+// checks the time code id and the reserved sub-millisecond size
+fn check_p_field(p_field: u8) ->
+    Result<(), exception::Exception> {
+    if ((p_field & P_FIELD_TIME_CODE_MASK) != P_FIELD_TIME_CODE_CDS) ||
+       ((p_field & P_FIELD_SUB_MS_MASK) == P_FIELD_SUB_MS_MASK) {
+        return Err(exception::invalid_p_field("invalid p-field for CDS time", p_field));
+    }
+    Ok(())
+}
+// tells if a time format has an explicit p-field
+pub fn has_p_field(p_field: u8) -> bool {
+    check_p_field(p_field).is_ok() && ((p_field & P_FIELD_NO_EMBEDDING) == 0)
+}
+// size of the day segment
+pub fn get_day_size(p_field: u8) -> usize {
+    if (p_field & P_FIELD_DAY_24_BIT) == 0 {2} else {3}
+}
+// size of the sub-millisecond segment
+pub fn get_sub_ms_size(p_field: u8) -> usize {
+    match p_field & P_FIELD_SUB_MS_MASK {
+        0 => 0,
+        1 => 2,
+        _ => 4,
+    }
+}
+// netto data size without embedded p-field
+pub fn get_data_size(p_field: u8) ->
+    Result<usize, exception::Exception> {
+    check_p_field(p_field)?;
+    Ok(get_day_size(p_field) + 4 + get_sub_ms_size(p_field))
+}
+// brutto data size with embedded p-field (if embedded p-field)
+pub fn get_full_data_size(p_field: u8) ->
+    Result<usize, exception::Exception> {
+    let data_size = get_data_size(p_field)?;
+    if has_p_field(p_field) {
+        Ok(data_size + 1)
+    } else {
+        Ok(data_size)
+    }
+}
+// maximum value of the sub-millisecond segment
+fn get_max_sub_ms(p_field: u8) -> u32 {
+    match get_sub_ms_size(p_field) {
+        0 => 0,
+        2 => 999,
+        _ => 999999999,
+    }
+}
+// checks the segments against the limits of the time code
+fn check_values(p_field: u8, day: u32, ms_of_day: u32, sub_ms: u32) ->
+    Result<(), exception::Exception> {
+    let max_day = ((1_u64 << (8 * get_day_size(p_field))) - 1) as u32;
+    if day > max_day {
+        return Err(exception::value_out_of_range("CDS time day out of range",
+            day as i128, 0, max_day as i128));
+    }
+    if ms_of_day > MAX_MSEC_OF_DAY {
+        return Err(exception::value_out_of_range("CDS time milliseconds of day out of range",
+            ms_of_day as i128, 0, MAX_MSEC_OF_DAY as i128));
+    }
+    let max_sub_ms = get_max_sub_ms(p_field);
+    if sub_ms > max_sub_ms {
+        return Err(exception::value_out_of_range("CDS time sub-milliseconds out of range",
+            sub_ms as i128, 0, max_sub_ms as i128));
+    }
+    Ok(())
+}
+// reads a big endian value
+fn read_value(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0_u32, |value, &byte| (value << 8) + (byte as u32))
+}
+// writes a big endian value
+fn write_value(bytes: &mut [u8], value: u32) {
+    let mut value = value;
+    for byte in bytes.iter_mut().rev() {
+        *byte = (value & 0xFF) as u8;
+        value >>= 8;
+    }
+}
+
+///////////////////////
+// struct definition //
+///////////////////////
+#[derive(Copy, Clone, Debug)]
+pub struct Time {
+    p_field: u8,
+    day: u32,
+    ms_of_day: u32,
+    sub_ms: u32,
+}
+
+////////////////////////////
+// methods implementation //
+////////////////////////////
+impl Time {
+    // initialization from p_field
+    pub fn new_from_p_field(p_field: u8) ->
+        Result<Time, exception::Exception> {
+        check_p_field(p_field)?;
+        Ok(Time {
+            p_field,
+            day: 0,
+            ms_of_day: 0,
+            sub_ms: 0,
+        })
+    }
+    // init constructor, sub_ms is in usec or psec depending on the p_field
+    pub fn new_init(p_field: u8, day: u32, ms_of_day: u32, sub_ms: u32) ->
+        Result<Time, exception::Exception> {
+        let mut time = Time::new_from_p_field(p_field)?;
+        check_values(p_field, day, ms_of_day, sub_ms)?;
+        time.day = day;
+        time.ms_of_day = ms_of_day;
+        time.sub_ms = sub_ms;
+        Ok(time)
+    }
+    // initialization from p_field and timespec
+    pub fn new_from_timespec(p_field: u8, timespec: time::Timespec) ->
+        Result<Time, exception::Exception> {
+        let mut time = Time::new_from_p_field(p_field)?;
+        time.init_from_timespec(timespec)?;
+        Ok(time)
+    }
+    // initialization from bytes with embedded p-field
+    pub fn new_from_bytes(bytes: &[u8]) ->
+        Result<Time, exception::Exception> {
+        if bytes.is_empty() {
+            return Err(exception::out_of_bounds("p-field out of buffer", 0, 1, 0));
+        }
+        let p_field = bytes[0];
+        if !has_p_field(p_field) {
+            return Err(exception::invalid_p_field("invalid embedded p-field for CDS time", p_field));
+        }
+        let data_size = get_full_data_size(p_field)?;
+        if data_size > bytes.len() {
+            return Err(exception::out_of_bounds("CDS time out of buffer", 0, data_size, bytes.len()));
+        }
+        let mut time = Time::new_from_p_field(p_field)?;
+        time.init_from_bytes(bytes)?;
+        Ok(time)
+    }
+    // accessors
+    pub fn get_p_field(self) -> u8 {
+        self.p_field
+    }
+    pub fn get_day(self) -> u32 {
+        self.day
+    }
+    pub fn get_ms_of_day(self) -> u32 {
+        self.ms_of_day
+    }
+    pub fn get_sub_ms(self) -> u32 {
+        self.sub_ms
+    }
+    // initialization from timespec, leap seconds are not considered
+    pub fn init_from_timespec(&mut self, timespec: time::Timespec) ->
+        Result<(), exception::Exception> {
+        let sec = timespec.sec;
+        let nsec = timespec.nsec;
+        let max_day = (1_i64 << (8 * get_day_size(self.p_field))) - 1;
+        let max_sec = (max_day * SEC_PER_DAY) + SEC_PER_DAY - 1;
+        if (sec < 0) || (sec > max_sec) {
+            return Err(exception::value_out_of_range("CDS time seconds out of range",
+                sec as i128, 0, max_sec as i128));
+        }
+        if !(0..=999999999).contains(&nsec) {
+            return Err(exception::value_out_of_range("CDS time supports only positive second fractions",
+                nsec as i128, 0, 999999999));
+        }
+        self.day = (sec / SEC_PER_DAY) as u32;
+        self.ms_of_day = (((sec % SEC_PER_DAY) * 1000) as u32) + ((nsec / 1000000) as u32);
+        let nsec_of_ms = (nsec % 1000000) as u32;
+        self.sub_ms = match get_sub_ms_size(self.p_field) {
+            0 => 0,
+            2 => nsec_of_ms / 1000,
+            _ => nsec_of_ms * 1000,
+        };
+        Ok(())
+    }
+    // conversion to timespec
+    pub fn to_timespec(&self) -> time::Timespec {
+        let sec = ((self.day as i64) * SEC_PER_DAY) + ((self.ms_of_day / 1000) as i64);
+        let nsec_of_ms = match get_sub_ms_size(self.p_field) {
+            0 => 0,
+            2 => self.sub_ms * 1000,
+            _ => self.sub_ms / 1000,
+        };
+        let nsec = ((self.ms_of_day % 1000) * 1000000) + nsec_of_ms;
+        time::Timespec::new(sec, nsec as i32)
+    }
+    // initialization from bytes, skips p-field (if embedded p-field),
+    // the decoded segments are checked like in new_init
+    pub fn init_from_bytes(&mut self, bytes: &[u8]) ->
+        Result<(), exception::Exception> {
+        let mut pos = if has_p_field(self.p_field) {1} else {0};
+        let day_size = get_day_size(self.p_field);
+        let day = read_value(&bytes[pos..(pos + day_size)]);
+        pos += day_size;
+        let ms_of_day = read_value(&bytes[pos..(pos + 4)]);
+        pos += 4;
+        let sub_ms = read_value(&bytes[pos..(pos + get_sub_ms_size(self.p_field))]);
+        check_values(self.p_field, day, ms_of_day, sub_ms)?;
+        self.day = day;
+        self.ms_of_day = ms_of_day;
+        self.sub_ms = sub_ms;
+        Ok(())
+    }
+    // update the contents to bytes incl. p-field (if embedded p-field)
+    pub fn update_to_bytes(&self, bytes: &mut [u8]) {
+        let mut pos = 0;
+        if has_p_field(self.p_field) {
+            bytes[0] = self.p_field;
+            pos = 1;
+        }
+        let day_size = get_day_size(self.p_field);
+        write_value(&mut bytes[pos..(pos + day_size)], self.day);
+        pos += day_size;
+        write_value(&mut bytes[pos..(pos + 4)], self.ms_of_day);
+        pos += 4;
+        write_value(&mut bytes[pos..(pos + get_sub_ms_size(self.p_field))], self.sub_ms);
+    }
+    // dumps the contents to a string
+    pub fn dump_str(&self) -> String {
+        let prefix = if has_p_field(self.p_field) {"L"} else {"T"};
+        let epoch = if (self.p_field & P_FIELD_EPOCH) == 0 {1} else {2};
+        let sub_ms_size = get_sub_ms_size(self.p_field);
+        let name = format!("{}{}Time{}4{}", prefix, epoch, get_day_size(self.p_field), sub_ms_size);
+        if sub_ms_size == 0 {
+            format!("{}({},{})", name, self.day, self.ms_of_day)
+        } else {
+            format!("{}({},{},{})", name, self.day, self.ms_of_day, self.sub_ms)
+        }
+    }
+}
+// trait implementation
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.dump_str())
+    }
+}
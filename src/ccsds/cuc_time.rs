@@ -13,8 +13,17 @@
 // CUC TIME - CCSDS unsegmented time code                                     *
 //                                                                            *
 // Correlation to an epoch must be done explicitly via use of util::tco       *
+//                                                                            *
+// Any p-field with 1 to 4 coarse and 0 to 3 fine octets is supported, the    *
+// p-field extension octet allows up to 7 coarse and 10 fine octets.          *
+//                                                                            *
+// Fine time conversions use exact integer arithmetic, the rounding is        *
+// selectable (truncate, nearest, ceil).                                      *
 //*****************************************************************************
+use std::cmp;
 use std::fmt;
+use std::hash;
+use std::ops;
 use std::u32;
 use time;
 use util::exception;
@@ -40,18 +49,155 @@ pub const T2_TIME_4_0: u8 = 0xac; // epoch: agency-def., 0 fine b.
 pub const T2_TIME_4_1: u8 = 0xad; // epoch: agency-def., 1 fine b.
 pub const T2_TIME_4_2: u8 = 0xae; // epoch: agency-def., 2 fine b.
 pub const T2_TIME_4_3: u8 = 0xaf; // epoch: agency-def., 3 fine b.
+// time code identification in the p-field
+pub const TIME_CODE_ID_1958: u8 = 1;   // epoch: 1.1.1958
+pub const TIME_CODE_ID_AGENCY: u8 = 2; // epoch: agency-defined
+// limits of the generic time codes (with p-field extension)
+pub const MAX_COARSE_OCTETS: usize = 7;
+pub const MAX_FINE_OCTETS: usize = 10;
 // for internal use
-const CUCFINE3_TO_NSEC: f64 = 1000000000_f64 /   16777216_f64;
-const CUCFINE2_TO_NSEC: f64 = 1000000000_f64 /      65536_f64;
-const CUCFINE1_TO_NSEC: f64 = 1000000000_f64 /        256_f64;
-const NSEC_TO_CUCFINE3: f64 =   16777216_f64 / 1000000000_f64;
-const NSEC_TO_CUCFINE2: f64 =      65536_f64 / 1000000000_f64;
-const NSEC_TO_CUCFINE1: f64 =        256_f64 / 1000000000_f64;
+const P_FIELD_EXTENSION: u8 = 0x80;
+const NSEC_PER_SEC: u128 = 1000000000;
 
 //////////////////
 // helper types //
 //////////////////
 
+// rounding of the fine time, when the resolution is reduced
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    Truncate,
+    Nearest,
+    Ceil,
+}
+
+// time code format, described by the p-field
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Format {
+    pub time_code_id: u8,
+    pub coarse_octets: usize,
+    pub fine_octets: usize,
+    pub embedded_p_field: bool,
+}
+impl Format {
+    // init constructor
+    pub fn new(
+        time_code_id: u8,
+        coarse_octets: usize,
+        fine_octets: usize,
+        embedded_p_field: bool) -> Result<Format, exception::Exception> {
+        if (time_code_id != TIME_CODE_ID_1958) && (time_code_id != TIME_CODE_ID_AGENCY) {
+            return Err(exception::value_out_of_range("invalid time code id for CUC time",
+                time_code_id as i128, TIME_CODE_ID_1958 as i128, TIME_CODE_ID_AGENCY as i128));
+        }
+        if !(1..=MAX_COARSE_OCTETS).contains(&coarse_octets) {
+            return Err(exception::value_out_of_range("invalid number of coarse octets for CUC time",
+                coarse_octets as i128, 1, MAX_COARSE_OCTETS as i128));
+        }
+        if fine_octets > MAX_FINE_OCTETS {
+            return Err(exception::value_out_of_range("invalid number of fine octets for CUC time",
+                fine_octets as i128, 0, MAX_FINE_OCTETS as i128));
+        }
+        Ok(Format {
+            time_code_id,
+            coarse_octets,
+            fine_octets,
+            embedded_p_field,
+        })
+    }
+    // initialization from a single octet p-field, the extension bit marks
+    // time codes without embedded p-field (see T1_TIME_4_0, ...)
+    pub fn new_from_p_field(p_field: u8) ->
+        Result<Format, exception::Exception> {
+        let format = decode_p_field(p_field);
+        match Format::new(
+            format.time_code_id,
+            format.coarse_octets,
+            format.fine_octets,
+            format.embedded_p_field) {
+            Err(_) => Err(exception::invalid_p_field("invalid p-field for CUC time", p_field)),
+            Ok(format) => Ok(format),
+        }
+    }
+    // initialization from an embedded p-field (incl. extension octet)
+    pub fn new_from_bytes(bytes: &[u8]) ->
+        Result<Format, exception::Exception> {
+        if bytes.is_empty() {
+            return Err(exception::out_of_bounds("p-field out of buffer", 0, 1, 0));
+        }
+        let p_field = bytes[0];
+        let mut format = decode_p_field(p_field);
+        if (p_field & P_FIELD_EXTENSION) != 0 {
+            if bytes.len() < 2 {
+                return Err(exception::out_of_bounds("p-field extension out of buffer", 1, 1, bytes.len()));
+            }
+            let p_field_ext = bytes[1];
+            if (p_field_ext & P_FIELD_EXTENSION) != 0 {
+                return Err(exception::invalid_p_field("unsupported further p-field extension for CUC time", p_field_ext));
+            }
+            format.coarse_octets += ((p_field_ext >> 5) & 0x03) as usize;
+            format.fine_octets += ((p_field_ext >> 2) & 0x07) as usize;
+        }
+        match Format::new(
+            format.time_code_id,
+            format.coarse_octets,
+            format.fine_octets,
+            true) {
+            Err(_) => Err(exception::invalid_p_field("invalid p-field for CUC time", p_field)),
+            Ok(format) => Ok(format),
+        }
+    }
+    // tells if the p-field needs the extension octet
+    pub fn is_extended(&self) -> bool {
+        (self.coarse_octets > 4) || (self.fine_octets > 3)
+    }
+    // p-field octets as defined in CCSDS 301.0 (1 or 2 octets)
+    pub fn get_p_field_octets(&self) -> Vec<u8> {
+        let coarse_octets = self.coarse_octets.min(4);
+        let fine_octets = self.fine_octets.min(3);
+        let p_field = (self.time_code_id << 4) |
+                      (((coarse_octets - 1) as u8) << 2) |
+                      (fine_octets as u8);
+        if !self.is_extended() {
+            return vec![p_field];
+        }
+        let p_field_ext = (((self.coarse_octets - coarse_octets) as u8) << 5) |
+                          (((self.fine_octets - fine_octets) as u8) << 2);
+        vec![p_field | P_FIELD_EXTENSION, p_field_ext]
+    }
+    // single octet p-field, with the extension bit set for time codes
+    // without embedded p-field (not unique for extended time codes)
+    pub fn get_p_field(&self) -> u8 {
+        let p_field = self.get_p_field_octets()[0];
+        if self.embedded_p_field {
+            p_field
+        } else {
+            p_field | P_FIELD_EXTENSION
+        }
+    }
+    // size of the p-field in the buffer
+    pub fn get_p_field_size(&self) -> usize {
+        match (self.embedded_p_field, self.is_extended()) {
+            (false, _) => 0,
+            (true, false) => 1,
+            (true, true) => 2,
+        }
+    }
+    // netto data size without embedded p-field
+    pub fn get_data_size(&self) -> usize {
+        self.coarse_octets + self.fine_octets
+    }
+    // brutto data size with embedded p-field (if embedded p-field)
+    pub fn get_full_data_size(&self) -> usize {
+        self.get_p_field_size() + self.get_data_size()
+    }
+    // name in the style of the time code variants, e.g. L1Time32
+    pub fn get_name(&self) -> String {
+        let prefix = if self.embedded_p_field {"L"} else {"T"};
+        format!("{}{}Time{}{}", prefix, self.time_code_id, self.coarse_octets, self.fine_octets)
+    }
+}
+
 // coarse time (seconds) with 4 bytes
 #[derive(Copy, Clone, Debug)]
 pub struct CoarseTime {
@@ -87,11 +233,13 @@ impl CoarseTime {
     pub fn init_from_sec(&mut self, sec: i64) ->
         Result<(), exception::Exception> {
         if sec < 0 {
-            return Err(exception::raise("CUC time supports only positive seconds"));
+            return Err(exception::value_out_of_range("CUC time supports only positive seconds",
+                sec as i128, 0, u32::MAX as i128));
         }
         if sec > (u32::MAX as i64) {
-            return Err(exception::raise(
-                &format!("CUC time supports positive seconds up to {}", u32::MAX)));
+            return Err(exception::value_out_of_range(
+                &format!("CUC time supports positive seconds up to {}", u32::MAX),
+                sec as i128, 0, u32::MAX as i128));
         }
         // convert coarse time
         self.t_coarse3 = (sec & 0xFF) as u8;
@@ -146,14 +294,19 @@ impl FineTime0 {
     // initialization from nano seconds
     pub fn init_from_nsec(&mut self, nsec: i32) ->
         Result<(), exception::Exception> {
-        if nsec < 0 {
-            return Err(exception::raise("CUC time supports only positive second fractions"));
-        }
-        // convert fine time --> nothing to do
+        self.init_from_nsec_with(nsec, RoundingMode::Truncate)
+    }
+    // initialization from nano seconds with the given rounding
+    pub fn init_from_nsec_with(&mut self, nsec: i32, rounding: RoundingMode) ->
+        Result<(), exception::Exception> {
+        // convert fine time --> only the range is checked
+        nsec_to_fine(nsec, 0, rounding)?;
         Ok(())
     }
     // conversion to nano seconds
     pub fn to_nsec(&self) -> i32 {0}
+    // conversion to nano seconds with the given rounding
+    pub fn to_nsec_with(&self, _rounding: RoundingMode) -> i32 {0}
     // initialization from bytes
     pub fn init_from_bytes(&mut self, _bytes: &[u8]) {}
     // update the contents to bytes
@@ -187,18 +340,25 @@ impl FineTime1 {
     // initialization from nano seconds
     pub fn init_from_nsec(&mut self, nsec: i32) ->
         Result<(), exception::Exception> {
-        if nsec < 0 {
-            return Err(exception::raise("CUC time supports only positive second fractions"));
-        }
+        self.init_from_nsec_with(nsec, RoundingMode::Truncate)
+    }
+    // initialization from nano seconds with the given rounding
+    pub fn init_from_nsec_with(&mut self, nsec: i32, rounding: RoundingMode) ->
+        Result<(), exception::Exception> {
         // convert fine time
-        let cuc_time_fine = ((nsec as f64) * NSEC_TO_CUCFINE1) as u64;
+        let cuc_time_fine = nsec_to_fine(nsec, 1, rounding)?;
         self.t_fine0 = (cuc_time_fine & 0xFF) as u8;
         Ok(())
     }
-    // conversion to nano seconds
+    // conversion to nano seconds (truncated)
     pub fn to_nsec(&self) -> i32 {
-        let cuc_time_fine = (self.t_fine0 as u64) * 0x000001_u64;
-        ((cuc_time_fine as f64) * CUCFINE1_TO_NSEC) as i32
+        self.to_nsec_with(RoundingMode::Truncate)
+    }
+    // conversion to nano seconds with the given rounding,
+    // returns 1000000000 when the rounding reaches the next second
+    pub fn to_nsec_with(&self, rounding: RoundingMode) -> i32 {
+        let cuc_time_fine = self.t_fine0 as u128;
+        fine_to_nsec(cuc_time_fine, 1, rounding)
     }
     // initialization from bytes
     pub fn init_from_bytes(&mut self, bytes: &[u8]) {
@@ -240,21 +400,28 @@ impl FineTime2 {
     // initialization from nano seconds
     pub fn init_from_nsec(&mut self, nsec: i32) ->
         Result<(), exception::Exception> {
-        if nsec < 0 {
-            return Err(exception::raise("CUC time supports only positive second fractions"));
-        }
+        self.init_from_nsec_with(nsec, RoundingMode::Truncate)
+    }
+    // initialization from nano seconds with the given rounding
+    pub fn init_from_nsec_with(&mut self, nsec: i32, rounding: RoundingMode) ->
+        Result<(), exception::Exception> {
         // convert fine time
-        let cuc_time_fine = ((nsec as f64) * NSEC_TO_CUCFINE2) as u64;
+        let cuc_time_fine = nsec_to_fine(nsec, 2, rounding)?;
         self.t_fine1 = (cuc_time_fine & 0xFF) as u8;
         let cuc_time_fine = cuc_time_fine >> 8;
         self.t_fine0 = (cuc_time_fine & 0xFF) as u8;
         Ok(())
     }
-    // conversion to nano seconds
+    // conversion to nano seconds (truncated)
     pub fn to_nsec(&self) -> i32 {
-        let cuc_time_fine = ((self.t_fine0 as u64) * 0x000100_u64) +
-                            ((self.t_fine1 as u64) * 0x000001_u64);
-        ((cuc_time_fine as f64) * CUCFINE2_TO_NSEC) as i32
+        self.to_nsec_with(RoundingMode::Truncate)
+    }
+    // conversion to nano seconds with the given rounding,
+    // returns 1000000000 when the rounding reaches the next second
+    pub fn to_nsec_with(&self, rounding: RoundingMode) -> i32 {
+        let cuc_time_fine = ((self.t_fine0 as u128) * 0x000100_u128) +
+                            (self.t_fine1 as u128);
+        fine_to_nsec(cuc_time_fine, 2, rounding)
     }
     // initialization from bytes
     pub fn init_from_bytes(&mut self, bytes: &[u8]) {
@@ -302,11 +469,13 @@ impl FineTime3 {
     // initialization from nano seconds
     pub fn init_from_nsec(&mut self, nsec: i32) ->
         Result<(), exception::Exception> {
-        if nsec < 0 {
-            return Err(exception::raise("CUC time supports only positive second fractions"));
-        }
+        self.init_from_nsec_with(nsec, RoundingMode::Truncate)
+    }
+    // initialization from nano seconds with the given rounding
+    pub fn init_from_nsec_with(&mut self, nsec: i32, rounding: RoundingMode) ->
+        Result<(), exception::Exception> {
         // convert fine time
-        let cuc_time_fine = ((nsec as f64) * NSEC_TO_CUCFINE3) as u64;
+        let cuc_time_fine = nsec_to_fine(nsec, 3, rounding)?;
         self.t_fine2 = (cuc_time_fine & 0xFF) as u8;
         let cuc_time_fine = cuc_time_fine >> 8;
         self.t_fine1 = (cuc_time_fine & 0xFF) as u8;
@@ -314,12 +483,26 @@ impl FineTime3 {
         self.t_fine0 = (cuc_time_fine & 0xFF) as u8;
         Ok(())
     }
-    // conversion to nano seconds
+    // conversion to nano seconds (truncated)
     pub fn to_nsec(&self) -> i32 {
-        let cuc_time_fine = ((self.t_fine0 as u64) * 0x010000_u64) +
-                            ((self.t_fine1 as u64) * 0x000100_u64) +
-                            ((self.t_fine2 as u64) * 0x000001_u64);
-        ((cuc_time_fine as f64) * CUCFINE3_TO_NSEC) as i32
+        self.to_nsec_with(RoundingMode::Truncate)
+    }
+    // conversion to nano seconds with the given rounding,
+    // returns 1000000000 when the rounding reaches the next second
+    pub fn to_nsec_with(&self, rounding: RoundingMode) -> i32 {
+        let cuc_time_fine = ((self.t_fine0 as u128) * 0x010000_u128) +
+                            ((self.t_fine1 as u128) * 0x000100_u128) +
+                            (self.t_fine2 as u128);
+        fine_to_nsec(cuc_time_fine, 3, rounding)
+    }
+    // conversion to truncated nano seconds and the remainder
+    // in units of 2^-24 nano seconds, this is exact
+    pub fn to_nsec_with_remainder(&self) -> (i32, u32) {
+        let cuc_time_fine = ((self.t_fine0 as u128) << 16) +
+                            ((self.t_fine1 as u128) << 8) +
+                            (self.t_fine2 as u128);
+        let nsec_fine = cuc_time_fine * NSEC_PER_SEC;
+        ((nsec_fine >> 24) as i32, (nsec_fine & 0xFFFFFF) as u32)
     }
     // initialization from bytes
     pub fn init_from_bytes(&mut self, bytes: &[u8]) {
@@ -339,72 +522,190 @@ impl FineTime3 {
     }
 }
 
+// coarse time (seconds) with 1 to 7 bytes, used by generic time codes
+#[derive(Copy, Clone, Debug)]
+pub struct CoarseTimeN {
+    pub octets: usize,
+    pub t_coarse: [u8; MAX_COARSE_OCTETS],
+}
+impl CoarseTimeN {
+    // default constructor
+    pub fn new(octets: usize) -> CoarseTimeN {
+        CoarseTimeN {
+            octets,
+            t_coarse: [0; MAX_COARSE_OCTETS],
+        }
+    }
+    // initialization from seconds
+    pub fn init_from_sec(&mut self, sec: i64) ->
+        Result<(), exception::Exception> {
+        let max_sec = ((1_u64 << (8 * self.octets)) - 1) as i64;
+        if sec < 0 {
+            return Err(exception::value_out_of_range("CUC time supports only positive seconds",
+                sec as i128, 0, max_sec as i128));
+        }
+        if sec > max_sec {
+            return Err(exception::value_out_of_range(
+                &format!("CUC time supports positive seconds up to {}", max_sec),
+                sec as i128, 0, max_sec as i128));
+        }
+        // convert coarse time
+        let mut sec = sec;
+        for pos in (0..self.octets).rev() {
+            self.t_coarse[pos] = (sec & 0xFF) as u8;
+            sec >>= 8;
+        }
+        Ok(())
+    }
+    // conversion to seconds
+    pub fn to_sec(&self) -> i64 {
+        self.t_coarse[..self.octets].iter().fold(0_i64, |sec, &byte| (sec << 8) + (byte as i64))
+    }
+    // initialization from bytes
+    pub fn init_from_bytes(&mut self, bytes: &[u8]) {
+        self.t_coarse[..self.octets].copy_from_slice(&bytes[..self.octets]);
+    }
+    // update the contents to bytes
+    pub fn update_to_bytes(&self, bytes: &mut [u8]) {
+        bytes[..self.octets].copy_from_slice(&self.t_coarse[..self.octets]);
+    }
+    // dumps the contents to a string
+    pub fn dump_str(&self) -> String {
+        let octets: Vec<String> = self.t_coarse[..self.octets].iter().map(|byte| byte.to_string()).collect();
+        format!("({})", octets.join(","))
+    }
+}
+
+// fine time (second fractions) with 0 to 10 bytes, used by generic time codes
+#[derive(Copy, Clone, Debug)]
+pub struct FineTimeN {
+    pub octets: usize,
+    pub t_fine: [u8; MAX_FINE_OCTETS],
+}
+impl FineTimeN {
+    // default constructor
+    pub fn new(octets: usize) -> FineTimeN {
+        FineTimeN {
+            octets,
+            t_fine: [0; MAX_FINE_OCTETS],
+        }
+    }
+    // initialization from nano seconds
+    pub fn init_from_nsec(&mut self, nsec: i32) ->
+        Result<(), exception::Exception> {
+        self.init_from_nsec_with(nsec, RoundingMode::Truncate)
+    }
+    // initialization from nano seconds with the given rounding
+    pub fn init_from_nsec_with(&mut self, nsec: i32, rounding: RoundingMode) ->
+        Result<(), exception::Exception> {
+        // convert fine time
+        let mut cuc_time_fine = nsec_to_fine(nsec, self.octets, rounding)?;
+        for pos in (0..self.octets).rev() {
+            self.t_fine[pos] = (cuc_time_fine & 0xFF) as u8;
+            cuc_time_fine >>= 8;
+        }
+        Ok(())
+    }
+    // conversion to nano seconds
+    pub fn to_nsec(&self) -> i32 {
+        self.to_nsec_with(RoundingMode::Truncate)
+    }
+    // conversion to nano seconds with the given rounding,
+    // returns 1000000000 when the rounding reaches the next second
+    pub fn to_nsec_with(&self, rounding: RoundingMode) -> i32 {
+        let cuc_time_fine = self.t_fine[..self.octets].iter().fold(0_u128, |fine, &byte| (fine << 8) + (byte as u128));
+        fine_to_nsec(cuc_time_fine, self.octets, rounding)
+    }
+    // initialization from bytes
+    pub fn init_from_bytes(&mut self, bytes: &[u8]) {
+        self.t_fine[..self.octets].copy_from_slice(&bytes[..self.octets]);
+    }
+    // update the contents to bytes
+    pub fn update_to_bytes(&self, bytes: &mut [u8]) {
+        bytes[..self.octets].copy_from_slice(&self.t_fine[..self.octets]);
+    }
+    // dumps the contents to a string
+    pub fn dump_str(&self) -> String {
+        let octets: Vec<String> = self.t_fine[..self.octets].iter().map(|byte| byte.to_string()).collect();
+        format!("({})", octets.join(","))
+    }
+}
+
 //////////////////////
 // helper functions //
 //////////////////////
 
 // tells if a time format has an explicit p-field
 pub fn has_p_field(p_field: u8) -> bool {
-    match p_field {
-        L1_TIME_4_0 |
-        L1_TIME_4_1 |
-        L1_TIME_4_2 |
-        L1_TIME_4_3 |
-        L2_TIME_4_0 |
-        L2_TIME_4_1 |
-        L2_TIME_4_2 |
-        L2_TIME_4_3 => true,
-        _ => false,
+    match Format::new_from_p_field(p_field) {
+        Err(_) => false,
+        Ok(format) => format.embedded_p_field,
     }
 }
 // netto data size without embedded p-field
 pub fn get_data_size(p_field: u8) ->
     Result<usize, exception::Exception> {
-    match p_field {
-        L1_TIME_4_0 => Ok(4),
-        L1_TIME_4_1 => Ok(5),
-        L1_TIME_4_2 => Ok(6),
-        L1_TIME_4_3 => Ok(7),
-        L2_TIME_4_0 => Ok(4),
-        L2_TIME_4_1 => Ok(5),
-        L2_TIME_4_2 => Ok(6),
-        L2_TIME_4_3 => Ok(7),
-        T1_TIME_4_0 => Ok(4),
-        T1_TIME_4_1 => Ok(5),
-        T1_TIME_4_2 => Ok(6),
-        T1_TIME_4_3 => Ok(7),
-        T2_TIME_4_0 => Ok(4),
-        T2_TIME_4_1 => Ok(5),
-        T2_TIME_4_2 => Ok(6),
-        T2_TIME_4_3 => Ok(7),
-        _ => Err(exception::raise("invalid p-field for CUC time")),
+    match Format::new_from_p_field(p_field) {
+        Err(err) => Err(err),
+        Ok(format) => Ok(format.get_data_size()),
     }
 }
 // brutto data size with embedded p-field (if embedded p-field)
 pub fn get_full_data_size(p_field: u8) ->
     Result<usize, exception::Exception> {
-    match p_field {
-        L1_TIME_4_0 => Ok(5),
-        L1_TIME_4_1 => Ok(6),
-        L1_TIME_4_2 => Ok(7),
-        L1_TIME_4_3 => Ok(8),
-        L2_TIME_4_0 => Ok(5),
-        L2_TIME_4_1 => Ok(6),
-        L2_TIME_4_2 => Ok(7),
-        L2_TIME_4_3 => Ok(8),
-        T1_TIME_4_0 => Ok(4),
-        T1_TIME_4_1 => Ok(5),
-        T1_TIME_4_2 => Ok(6),
-        T1_TIME_4_3 => Ok(7),
-        T2_TIME_4_0 => Ok(4),
-        T2_TIME_4_1 => Ok(5),
-        T2_TIME_4_2 => Ok(6),
-        T2_TIME_4_3 => Ok(7),
-        _ => Err(exception::raise("invalid p-field for CUC time")),
+    match Format::new_from_p_field(p_field) {
+        Err(err) => Err(err),
+        Ok(format) => Ok(format.get_full_data_size()),
     }
 }
-
-
+// decodes the first p-field octet without consistency checks
+fn decode_p_field(p_field: u8) -> Format {
+    Format {
+        time_code_id: (p_field >> 4) & 0x07,
+        coarse_octets: (((p_field >> 2) & 0x03) + 1) as usize,
+        fine_octets: (p_field & 0x03) as usize,
+        embedded_p_field: (p_field & P_FIELD_EXTENSION) == 0,
+    }
+}
+// integer division with rounding
+fn round_div(numerator: u128, denominator: u128, rounding: RoundingMode) -> u128 {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    let round_up = match rounding {
+        RoundingMode::Truncate => false,
+        RoundingMode::Nearest => remainder >= (denominator - remainder),
+        RoundingMode::Ceil => remainder > 0,
+    };
+    if round_up {quotient + 1} else {quotient}
+}
+// checks the nano seconds of a second fraction
+fn check_nsec(nsec: i32) ->
+    Result<(), exception::Exception> {
+    if nsec < 0 {
+        return Err(exception::value_out_of_range("CUC time supports only positive second fractions",
+            nsec as i128, 0, 999999999));
+    }
+    if nsec > 999999999 {
+        return Err(exception::value_out_of_range("CUC time supports second fractions below one second",
+            nsec as i128, 0, 999999999));
+    }
+    Ok(())
+}
+// exact conversion of nano seconds into a fine time with the given bytes
+fn nsec_to_fine(nsec: i32, octets: usize, rounding: RoundingMode) ->
+    Result<u128, exception::Exception> {
+    check_nsec(nsec)?;
+    let cuc_time_fine = round_div((nsec as u128) << (8 * octets), NSEC_PER_SEC, rounding);
+    if (cuc_time_fine >> (8 * octets)) != 0 {
+        return Err(exception::value_out_of_range("CUC fine time overflow, the rounding needs a carry into the coarse time",
+            cuc_time_fine as i128, 0, ((1_u128 << (8 * octets)) - 1) as i128));
+    }
+    Ok(cuc_time_fine)
+}
+// exact conversion of a fine time with the given bytes into nano seconds
+fn fine_to_nsec(cuc_time_fine: u128, octets: usize, rounding: RoundingMode) -> i32 {
+    round_div(cuc_time_fine * NSEC_PER_SEC, 1_u128 << (8 * octets), rounding) as i32
+}
 
 /////////////////////
 // enum definition //
@@ -477,6 +778,12 @@ pub enum Time {
         coarse_time: CoarseTime,
         fine_time: FineTime3,
     },
+    // all other time codes (incl. p-field extension)
+    Generic {
+        format: Format,
+        coarse_time: CoarseTimeN,
+        fine_time: FineTimeN,
+    },
 }
 
 ////////////////////////////
@@ -853,27 +1160,78 @@ impl Time {
                 t_fine2),
         }
     }
+    // initialization from format, the fixed size variants are used when possible
+    pub fn new_from_format(format: Format) -> Time {
+        if !format.is_extended() {
+            if let Some(time) = Time::new_fixed(format.get_p_field()) {
+                return time;
+            }
+        }
+        Time::Generic {
+            format,
+            coarse_time: CoarseTimeN::new(format.coarse_octets),
+            fine_time: FineTimeN::new(format.fine_octets),
+        }
+    }
+    // initialization from format and the coarse and fine time bytes
+    pub fn new_from_format_init(format: Format, t_coarse: &[u8], t_fine: &[u8]) ->
+        Result<Time, exception::Exception> {
+        if t_coarse.len() != format.coarse_octets {
+            return Err(exception::length_mismatch("unexpected number of coarse time bytes",
+                format.coarse_octets, t_coarse.len()));
+        }
+        if t_fine.len() != format.fine_octets {
+            return Err(exception::length_mismatch("unexpected number of fine time bytes",
+                format.fine_octets, t_fine.len()));
+        }
+        let mut bytes = format.get_p_field_octets();
+        bytes.truncate(format.get_p_field_size());
+        bytes.extend_from_slice(t_coarse);
+        bytes.extend_from_slice(t_fine);
+        let mut time = Time::new_from_format(format);
+        time.init_from_bytes(&bytes);
+        Ok(time)
+    }
+    // initialization from bytes with embedded p-field (incl. extension)
+    pub fn new_from_bytes(bytes: &[u8]) ->
+        Result<Time, exception::Exception> {
+        let format = Format::new_from_bytes(bytes)?;
+        let data_size = format.get_full_data_size();
+        if data_size > bytes.len() {
+            return Err(exception::out_of_bounds("CUC time out of buffer", 0, data_size, bytes.len()));
+        }
+        let mut time = Time::new_from_format(format);
+        time.init_from_bytes(bytes);
+        Ok(time)
+    }
     // initialization from p_field
     pub fn new_from_p_field(p_field: u8) ->
         Result<Time, exception::Exception> {
+        match Format::new_from_p_field(p_field) {
+            Err(_) => Err(exception::invalid_p_field("invalid p-field for CUC time creation", p_field)),
+            Ok(format) => Ok(Time::new_from_format(format)),
+        }
+    }
+    // fixed size variant for the supported p_field constants
+    fn new_fixed(p_field: u8) -> Option<Time> {
         match p_field {
-            L1_TIME_4_0 => Ok(Time::new_l1_time_4_0()),
-            L1_TIME_4_1 => Ok(Time::new_l1_time_4_1()),
-            L1_TIME_4_2 => Ok(Time::new_l1_time_4_2()),
-            L1_TIME_4_3 => Ok(Time::new_l1_time_4_3()),
-            L2_TIME_4_0 => Ok(Time::new_l2_time_4_0()),
-            L2_TIME_4_1 => Ok(Time::new_l2_time_4_1()),
-            L2_TIME_4_2 => Ok(Time::new_l2_time_4_2()),
-            L2_TIME_4_3 => Ok(Time::new_l2_time_4_3()),
-            T1_TIME_4_0 => Ok(Time::new_t1_time_4_0()),
-            T1_TIME_4_1 => Ok(Time::new_t1_time_4_1()),
-            T1_TIME_4_2 => Ok(Time::new_t1_time_4_2()),
-            T1_TIME_4_3 => Ok(Time::new_t1_time_4_3()),
-            T2_TIME_4_0 => Ok(Time::new_t2_time_4_0()),
-            T2_TIME_4_1 => Ok(Time::new_t2_time_4_1()),
-            T2_TIME_4_2 => Ok(Time::new_t2_time_4_2()),
-            T2_TIME_4_3 => Ok(Time::new_t2_time_4_3()),
-            _ => Err(exception::raise("invalid p-field for CUC time creation")),
+            L1_TIME_4_0 => Some(Time::new_l1_time_4_0()),
+            L1_TIME_4_1 => Some(Time::new_l1_time_4_1()),
+            L1_TIME_4_2 => Some(Time::new_l1_time_4_2()),
+            L1_TIME_4_3 => Some(Time::new_l1_time_4_3()),
+            L2_TIME_4_0 => Some(Time::new_l2_time_4_0()),
+            L2_TIME_4_1 => Some(Time::new_l2_time_4_1()),
+            L2_TIME_4_2 => Some(Time::new_l2_time_4_2()),
+            L2_TIME_4_3 => Some(Time::new_l2_time_4_3()),
+            T1_TIME_4_0 => Some(Time::new_t1_time_4_0()),
+            T1_TIME_4_1 => Some(Time::new_t1_time_4_1()),
+            T1_TIME_4_2 => Some(Time::new_t1_time_4_2()),
+            T1_TIME_4_3 => Some(Time::new_t1_time_4_3()),
+            T2_TIME_4_0 => Some(Time::new_t2_time_4_0()),
+            T2_TIME_4_1 => Some(Time::new_t2_time_4_1()),
+            T2_TIME_4_2 => Some(Time::new_t2_time_4_2()),
+            T2_TIME_4_3 => Some(Time::new_t2_time_4_3()),
+            _ => None,
         }
     }
     // initialization from p_field and timespec
@@ -888,6 +1246,13 @@ impl Time {
         };
         Ok(time)
     }
+    // initialization from p_field and timespec with the given rounding
+    pub fn new_from_timespec_with(p_field: u8, timespec: time::Timespec, rounding: RoundingMode) ->
+        Result<Time, exception::Exception> {
+        let mut time = Time::new_from_p_field(p_field)?;
+        time.init_from_timespec_with(timespec, rounding)?;
+        Ok(time)
+    }
     // accessor to p_field
     pub fn get_p_field(self) -> u8 {
         match self {
@@ -907,6 +1272,14 @@ impl Time {
             Time::T2Time41 {..} => T2_TIME_4_1,
             Time::T2Time42 {..} => T2_TIME_4_2,
             Time::T2Time43 {..} => T2_TIME_4_3,
+            Time::Generic {format, ..} => format.get_p_field(),
+        }
+    }
+    // accessor to the format, also describes extended time codes
+    pub fn get_format(&self) -> Format {
+        match self {
+            Time::Generic {format, ..} => *format,
+            _ => decode_p_field(self.get_p_field()),
         }
     }
     // initialization from timespec
@@ -931,6 +1304,7 @@ impl Time {
             Time::T2Time41 {coarse_time, ..} => coarse_time.init_from_sec(sec),
             Time::T2Time42 {coarse_time, ..} => coarse_time.init_from_sec(sec),
             Time::T2Time43 {coarse_time, ..} => coarse_time.init_from_sec(sec),
+            Time::Generic {coarse_time, ..} => coarse_time.init_from_sec(sec),
         } {
             return Err(err);
         };
@@ -953,6 +1327,7 @@ impl Time {
             Time::T2Time41 {fine_time, ..} => fine_time.init_from_nsec(nsec),
             Time::T2Time42 {fine_time, ..} => fine_time.init_from_nsec(nsec),
             Time::T2Time43 {fine_time, ..} => fine_time.init_from_nsec(nsec),
+            Time::Generic {fine_time, ..} => fine_time.init_from_nsec(nsec),
         }
     }
     // conversion to timespec
@@ -975,6 +1350,7 @@ impl Time {
             Time::T2Time41 {coarse_time, ..} => coarse_time.to_sec(),
             Time::T2Time42 {coarse_time, ..} => coarse_time.to_sec(),
             Time::T2Time43 {coarse_time, ..} => coarse_time.to_sec(),
+            Time::Generic {coarse_time, ..} => coarse_time.to_sec(),
         };
         // convert nano seconds
         let nsec = match self {
@@ -994,9 +1370,35 @@ impl Time {
             Time::T2Time41 {fine_time, ..} => fine_time.to_nsec(),
             Time::T2Time42 {fine_time, ..} => fine_time.to_nsec(),
             Time::T2Time43 {fine_time, ..} => fine_time.to_nsec(),
+            Time::Generic {fine_time, ..} => fine_time.to_nsec(),
         };
         time::Timespec::new(sec, nsec)
     }
+    // initialization from timespec with the given rounding,
+    // rounding up can carry into the coarse time
+    pub fn init_from_timespec_with(&mut self, timespec: time::Timespec, rounding: RoundingMode) ->
+        Result<(), exception::Exception> {
+        check_nsec(timespec.nsec)?;
+        let numerator = (timespec.nsec as u128) << (8 * MAX_FINE_OCTETS);
+        *self = Time::new_from_value(self.get_format(), timespec.sec as i128, numerator, NSEC_PER_SEC, rounding)?;
+        Ok(())
+    }
+    // conversion to timespec with the given rounding
+    pub fn to_timespec_with(&self, rounding: RoundingMode) -> time::Timespec {
+        let (coarse, fine) = self.get_value();
+        let nsec = fine_to_nsec(fine, MAX_FINE_OCTETS, rounding) as i64;
+        // the rounding can reach the next second
+        time::Timespec::new((coarse as i64) + (nsec / 1000000000), (nsec % 1000000000) as i32)
+    }
+    // conversion to a truncated timespec and the remainder in units of
+    // 2^-24 nano seconds, this is exact for up to 3 fine bytes
+    pub fn to_timespec_with_remainder(&self) -> (time::Timespec, u32) {
+        let (coarse, fine) = self.get_value();
+        let nsec_fine = fine * NSEC_PER_SEC;
+        let nsec = (nsec_fine >> (8 * MAX_FINE_OCTETS)) as i32;
+        let remainder = (nsec_fine & ((1_u128 << (8 * MAX_FINE_OCTETS)) - 1)) >> (8 * MAX_FINE_OCTETS - 24);
+        (time::Timespec::new(coarse as i64, nsec), remainder as u32)
+    }
     // initialization from bytes, skips p-field (if embedded p-field)
     pub fn init_from_bytes(&mut self, bytes: &[u8]) {
         match self {
@@ -1065,6 +1467,11 @@ impl Time {
                 coarse_time.init_from_bytes(&bytes);
                 fine_time.init_from_bytes(&bytes[4..]);
             },
+            Time::Generic {format, coarse_time, fine_time} => {
+                let pos = format.get_p_field_size();
+                coarse_time.init_from_bytes(&bytes[pos..]);
+                fine_time.init_from_bytes(&bytes[(pos + format.coarse_octets)..]);
+            },
         };
     }
     // update the contents to bytes incl. p-field (if embedded p-field)
@@ -1142,8 +1549,101 @@ impl Time {
                 coarse_time.update_to_bytes(bytes);
                 fine_time.update_to_bytes(&mut bytes[4..]);
             },
+            Time::Generic {format, coarse_time, fine_time} => {
+                let pos = format.get_p_field_size();
+                bytes[..pos].copy_from_slice(&format.get_p_field_octets()[..pos]);
+                coarse_time.update_to_bytes(&mut bytes[pos..]);
+                fine_time.update_to_bytes(&mut bytes[(pos + format.coarse_octets)..]);
+            },
         };
     }
+    // coarse time (seconds) and fine time in units of 2^-80 seconds,
+    // this is the exact value of all variants
+    fn get_value(&self) -> (u64, u128) {
+        let format = self.get_format();
+        let mut bytes = [0_u8; 2 + MAX_COARSE_OCTETS + MAX_FINE_OCTETS];
+        self.update_to_bytes(&mut bytes);
+        let coarse_pos = format.get_p_field_size();
+        let fine_pos = coarse_pos + format.coarse_octets;
+        let coarse = bytes[coarse_pos..fine_pos].iter().fold(0_u64, |value, &byte| (value << 8) + (byte as u64));
+        let fine = bytes[fine_pos..(fine_pos + format.fine_octets)].iter().fold(0_u128, |value, &byte| (value << 8) + (byte as u128));
+        (coarse, fine << (8 * (MAX_FINE_OCTETS - format.fine_octets)))
+    }
+    // creation from coarse time (seconds) and fine time in units of
+    // (numerator / denominator) * 2^-80 seconds, the fine time can exceed
+    // one second, the coarse time is checked for overflow
+    fn new_from_value(format: Format, coarse: i128, numerator: u128, denominator: u128, rounding: RoundingMode) ->
+        Result<Time, exception::Exception> {
+        let fine_shift = 8 * (MAX_FINE_OCTETS - format.fine_octets);
+        let fine = round_div(numerator, denominator << fine_shift, rounding);
+        let fine_per_sec = 1_u128 << (8 * format.fine_octets);
+        let coarse = coarse + ((fine / fine_per_sec) as i128);
+        let fine = fine % fine_per_sec;
+        let max_coarse = ((1_u64 << (8 * format.coarse_octets)) - 1) as i128;
+        if (coarse < 0) || (coarse > max_coarse) {
+            return Err(exception::value_out_of_range("CUC coarse time overflow", coarse, 0, max_coarse));
+        }
+        let mut bytes = format.get_p_field_octets();
+        bytes.truncate(format.get_p_field_size());
+        let pos = bytes.len();
+        bytes.resize(pos + format.get_data_size(), 0);
+        let mut value = coarse as u128;
+        for byte in bytes[pos..(pos + format.coarse_octets)].iter_mut().rev() {
+            *byte = (value & 0xFF) as u8;
+            value >>= 8;
+        }
+        let mut value = fine;
+        for byte in bytes[(pos + format.coarse_octets)..].iter_mut().rev() {
+            *byte = (value & 0xFF) as u8;
+            value >>= 8;
+        }
+        let mut time = Time::new_from_format(format);
+        time.init_from_bytes(&bytes);
+        Ok(time)
+    }
+    // adds a duration, the nano seconds are rounded to the fine time
+    pub fn add_duration(&self, duration: time::Duration, rounding: RoundingMode) ->
+        Result<Time, exception::Exception> {
+        let (coarse, fine) = self.get_value();
+        // split the duration into seconds and positive nano seconds
+        let mut sec = duration.num_seconds() as i128;
+        let mut nsec = (duration - time::Duration::seconds(sec as i64)).num_nanoseconds().unwrap_or(0) as i128;
+        if nsec < 0 {
+            sec -= 1;
+            nsec += NSEC_PER_SEC as i128;
+        }
+        let numerator = (fine * NSEC_PER_SEC) + ((nsec as u128) << (8 * MAX_FINE_OCTETS));
+        Time::new_from_value(self.get_format(), (coarse as i128) + sec, numerator, NSEC_PER_SEC, rounding)
+    }
+    // subtracts a duration, the nano seconds are rounded to the fine time
+    pub fn sub_duration(&self, duration: time::Duration, rounding: RoundingMode) ->
+        Result<Time, exception::Exception> {
+        self.add_duration(-duration, rounding)
+    }
+    // difference self - other, truncated to nano seconds
+    pub fn difference(&self, other: &Time) -> time::Duration {
+        let (coarse1, fine1) = self.get_value();
+        let (coarse2, fine2) = other.get_value();
+        let mut sec = (coarse1 as i64) - (coarse2 as i64);
+        let mut fine = (fine1 as i128) - (fine2 as i128);
+        if fine < 0 {
+            sec -= 1;
+            fine += 1_i128 << (8 * MAX_FINE_OCTETS);
+        }
+        let nsec = ((fine as u128) * NSEC_PER_SEC) >> (8 * MAX_FINE_OCTETS);
+        time::Duration::seconds(sec) + time::Duration::nanoseconds(nsec as i64)
+    }
+    // conversion to another variant, the epoch is not changed (see util::tco)
+    pub fn convert(&self, p_field: u8, rounding: RoundingMode) ->
+        Result<Time, exception::Exception> {
+        let format = Format::new_from_p_field(p_field)?;
+        self.convert_to_format(format, rounding)
+    }
+    pub fn convert_to_format(&self, format: Format, rounding: RoundingMode) ->
+        Result<Time, exception::Exception> {
+        let (coarse, fine) = self.get_value();
+        Time::new_from_value(format, coarse as i128, fine, 1, rounding)
+    }
     // dumps the contents to a string
     pub fn dump_str(&self) -> String {
         match self {
@@ -1195,6 +1695,9 @@ impl Time {
             Time::T2Time43 {coarse_time, fine_time} => {
                 format!("T2Time43({},{})", coarse_time.dump_str(), fine_time.dump_str())
             },
+            Time::Generic {format, coarse_time, fine_time} => {
+                format!("{}({},{})", format.get_name(), coarse_time.dump_str(), fine_time.dump_str())
+            },
         }
     }
 }
@@ -1204,3 +1707,33 @@ impl fmt::Display for Time {
         write!(f, "{}", self.dump_str())
     }
 }
+// comparison of the time values across variants,
+// the time code id (epoch) is not considered
+impl PartialEq for Time {
+    fn eq(&self, other: &Time) -> bool {
+        self.get_value() == other.get_value()
+    }
+}
+impl Eq for Time {}
+impl PartialOrd for Time {
+    fn partial_cmp(&self, other: &Time) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Time {
+    fn cmp(&self, other: &Time) -> cmp::Ordering {
+        self.get_value().cmp(&other.get_value())
+    }
+}
+impl hash::Hash for Time {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.get_value().hash(state);
+    }
+}
+// difference between two times
+impl ops::Sub for Time {
+    type Output = time::Duration;
+    fn sub(self, other: Time) -> time::Duration {
+        self.difference(&other)
+    }
+}
@@ -12,6 +12,9 @@
 //*****************************************************************************
 // Module configuration                                                       *
 //*****************************************************************************
+pub mod ascii_time;
 #[macro_use]
 pub mod c_packet;
+pub mod ccs_time;
+pub mod cds_time;
 pub mod cuc_time;
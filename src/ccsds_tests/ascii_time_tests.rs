@@ -0,0 +1,89 @@
+//*****************************************************************************
+// (C) 2018, Stefan Korner, Austria                                           *
+//                                                                            *
+// The Space Rust Library is free software; you can redistribute it and/or    *
+// modify it under the terms of the MIT License as published by the           *
+// Massachusetts Institute of Technology.                                     *
+//                                                                            *
+// The Space Rust Library is distributed in the hope that it will be useful,  *
+// but WITHOUT ANY WARRANTY; without even the implied warranty of             *
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the MIT License   *
+// for more details.                                                          *
+//*****************************************************************************
+// ASCII TIME - CCSDS ASCII time codes A and B - Unit tests                   *
+//*****************************************************************************
+use ccsds::ascii_time;
+use test_helpers::assert;
+use time;
+use util::exception;
+
+///////////////
+// functions //
+///////////////
+
+fn test_parse_error(time_str: &str) {
+    let error_message = ascii_time::parse_time(time_str).
+        expect_err("expected error did not happen");
+    println!("expected: {} for {}", error_message, time_str);
+    match *error_message.kind() {
+        exception::ErrorKind::ParseError {ref input} => assert::dump_string("input", input, time_str),
+        _ => panic!("unexpected error kind {:?}", error_message.kind())
+    }
+}
+
+fn test_range_error(time_str: &str, value: i128, max: i128) {
+    let error_message = ascii_time::parse_time(time_str).
+        expect_err("expected error did not happen");
+    println!("expected: {} for {}", error_message, time_str);
+    assert_eq!(*error_message.kind(), exception::ErrorKind::ValueOutOfRange {value, min: 1, max});
+}
+
+fn test_parse_ok(time_str: &str, expected_sec: i64, expected_nsec: i32) {
+    let time = ascii_time::parse_time(time_str).unwrap();
+    assert::dump_timespec(time_str, time, expected_sec, expected_nsec);
+}
+
+pub fn test() {
+    let test_time = time::Timespec::new(315968523, 123456789);
+    // format time tests
+    assert::dump_string("code A", &ascii_time::get_time_str_a(test_time, 0), "1980-01-06T01:02:03Z");
+    assert::dump_string("code A", &ascii_time::get_time_str_a(test_time, 3), "1980-01-06T01:02:03.123Z");
+    assert::dump_string("code A", &ascii_time::get_time_str_a(test_time, 12), "1980-01-06T01:02:03.123456789000Z");
+    assert::dump_string("code B", &ascii_time::get_time_str_b(test_time, 0), "1980-006T01:02:03Z");
+    assert::dump_string("code B", &ascii_time::get_time_str_b(test_time, 6), "1980-006T01:02:03.123456Z");
+    // parse time tests
+    test_parse_error("");
+    test_parse_error("1980-01-06");
+    test_parse_error("1980-01-06T01:02");
+    test_parse_error("1980-01-06T01:02:0x");
+    test_parse_error("1980-01-06T01:02:03.");
+    test_parse_error("1980-01-06T01:02:03.1x");
+    test_parse_error("1980-01-06T01:02:03Z1");
+    test_parse_error("1980-13-06T01:02:03Z");
+    test_parse_error("1980-xxxT01:02:03Z");
+    test_range_error("2021-02-30T00:00:00Z", 30, 28);
+    test_range_error("2020-04-31T00:00:00Z", 31, 30);
+    test_range_error("2021-366T00:00:00Z", 366, 365);
+    test_parse_ok("1970-01-01T00:00:00Z", 0, 0);
+    test_parse_ok("1970-001T00:00:00Z", 0, 0);
+    test_parse_ok("1980-01-06T01:02:03", 315968523, 0);
+    test_parse_ok("1980-01-06T01:02:03Z", 315968523, 0);
+    test_parse_ok("1980-01-06T01:02:03.1", 315968523, 100000000);
+    test_parse_ok("1980-01-06T01:02:03.123456789Z", 315968523, 123456789);
+    test_parse_ok("1980-006T01:02:03", 315968523, 0);
+    test_parse_ok("1980-006T01:02:03.12Z", 315968523, 120000000);
+    test_parse_ok("1980-006T01:02:03.123456789123Z", 315968523, 123456789);
+    test_parse_ok("2016-366T00:00:00Z", 1483142400, 0);
+    test_parse_ok("2016-12-31T00:00:00Z", 1483142400, 0);
+    // leap seconds are accepted and roll over into the next day
+    test_parse_ok("2016-12-31T23:59:60Z", 1483228800, 0);
+    test_parse_ok("2016-366T23:59:60Z", 1483228800, 0);
+    test_parse_ok("2015-06-30T23:59:60.5Z", 1435708800, 500000000);
+    // conversion to ASD time
+    assert::dump_string("ASD time", &ascii_time::get_asd_time_str("1980-01-06T01:02:03Z").unwrap(), "1980.006.01.02.03");
+    assert::dump_string("ASD time", &ascii_time::get_asd_time_str("1980-01-06T01:02:03.12Z").unwrap(), "1980.006.01.02.03.120");
+    assert::dump_string("ASD time", &ascii_time::get_asd_time_str("1980-006T01:02:03.1234").unwrap(), "1980.006.01.02.03.123400");
+    let error_message = ascii_time::get_asd_time_str("1980.006.01.02.03").
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+}
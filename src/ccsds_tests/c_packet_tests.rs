@@ -12,12 +12,17 @@
 //*****************************************************************************
 // CCSDS Stack - CCSDS Packet Module - Unit tests                             *
 //*****************************************************************************
+use ccsds::cds_time;
 use ccsds::cuc_time;
 use ccsds::c_packet;
 use ccsds::c_packet::PacketIntf;
+use ccsds_tests::cds_time_tests;
 use ccsds_tests::cuc_time_tests;
+use test_helpers::assert;
 use util::du;
 use util::du::DUintf;
+use util::exception;
+use util_tests::du_tests::assert_dump_du;
 
 def_cuc_time_accessor!(CUC_TIME_ACC1, 16, cuc_time::L2_TIME_4_3);
 def_cuc_time_accessor!(CUC_TIME_ACC_L1_4_0, 16, cuc_time::L1_TIME_4_0);
@@ -40,12 +45,52 @@ def_cuc_time_accessor!(CUC_TIME_ACC_ERR1, 16, 0);
 def_cuc_time_accessor!(CUC_TIME_ACC_ERR2, 25, cuc_time::L2_TIME_4_3);
 def_cuc_time_accessor!(CUC_TIME_ACC_ERR3, 26, cuc_time::T2_TIME_4_3);
 def_cuc_time_accessor!(CUC_TIME_ACC_ERR4, 16, cuc_time::L2_TIME_4_3);
+def_cds_time_accessor!(CDS_TIME_ACC1, 16, cds_time::L1_TIME_2_4_2);
+def_cds_time_accessor!(CDS_TIME_ACC2, 20, cds_time::T2_TIME_3_4_4);
+def_cds_time_accessor!(CDS_TIME_ACC_ERR1, 24, cds_time::L1_TIME_2_4_2);
 
 // some accessors from util::du to check compile cleaness
 def_unsigned_accessor!(UINT_ACC, 0, 0);
 def_big_unsigned_accessor!(BUINT_ACC, 0, 0);
+def_signed_accessor!(INT_ACC, 0, 0);
+def_big_signed_accessor!(BINT_ACC, 0, 0);
+def_float_accessor!(FLOAT_ACC, 0, 0);
+def_mil_float_accessor!(MIL_FLOAT_ACC, 0, 0);
+def_unsigned_le_accessor!(UINT_LE_ACC, 0, 0);
+def_big_unsigned_le_accessor!(BUINT_LE_ACC, 0, 0);
+def_signed_le_accessor!(INT_LE_ACC, 0, 0);
+def_big_signed_le_accessor!(BINT_LE_ACC, 0, 0);
+def_float_le_accessor!(FLOAT_LE_ACC, 0, 0);
 def_bit_accessor!(BIT_ACC,  0,  0);
+def_big_bit_accessor!(BBIT_ACC,  0,  0);
+def_signed_bit_accessor!(SBIT_ACC,  0,  0);
+def_big_signed_bit_accessor!(BSBIT_ACC,  0,  0);
 def_byte_accessor!(BYTE_ACC, 0, 0);
+def_string_accessor!(STR_ACC, 0, 0, du::StringPadding::Space);
+def_variable_octets_accessor!(VOCT_ACC, 0, 0);
+
+// declarative TM packet header, same layout as c_packet::TMpacket
+#[derive(DataUnit)]
+pub struct DerivedTMpacket<'a> {
+    buffer: du::HybridVector<'a>,
+    #[bits(0, 3)]
+    version_number: du::FieldMarker,
+    #[bits(3, 1)]
+    packet_type: du::FieldMarker,
+    #[bits(5, 11)]
+    application_process_id: du::FieldMarker,
+    #[bits(18, 14)]
+    sequence_control_count: du::FieldMarker,
+    #[unsigned(4, 2)]
+    packet_length: du::FieldMarker,
+    #[cuc_time(6, cuc_time::T2_TIME_4_3)]
+    packet_time: du::FieldMarker
+}
+
+pub fn assert_dump_derived_tm_packet(val_name: &str, val: &DerivedTMpacket, expected: &str) {
+    println!("{} = {}", val_name, val.dump_str());
+    assert_eq!(val.dump_str(), expected);
+}
 
 pub fn assert_dump_packet(val_name: &str, val: &c_packet::Packet, expected: &str) {
     println!("{} = {}", val_name, val.dump_str());
@@ -204,4 +249,227 @@ pub fn test() {
     test_cuc_time_get_error(CUC_TIME_ACC_ERR2);
     test_cuc_time_get_error(CUC_TIME_ACC_ERR3);
     test_cuc_time_get_error(CUC_TIME_ACC_ERR4);
+    // test access of CDS time
+    let mut packet = c_packet::Packet::new_alloc(32);
+    let cds_time = cds_time::Time::new_init(cds_time::L1_TIME_2_4_2, 3, 5123, 456).unwrap();
+    packet.set_cds_time_acc(CDS_TIME_ACC1, cds_time).unwrap();
+    assert_dump_packet("packet", &packet, "
+0000 00 00 00 00 00 19 00 00 00 00 00 00 00 00 00 00 ................
+0010 41 00 03 00 00 14 03 01 c8 00 00 00 00 00 00 00 A...............");
+    let cds_time2 = packet.get_cds_time_acc(CDS_TIME_ACC1).unwrap();
+    cds_time_tests::assert_dump_cds_time("cds_time2", cds_time2, cds_time);
+    let cds_time = cds_time::Time::new_init(cds_time::T2_TIME_3_4_4, 1, 2, 3).unwrap();
+    packet.set_cds_time_acc(CDS_TIME_ACC2, cds_time).unwrap();
+    let cds_time2 = packet.get_cds_time_acc(CDS_TIME_ACC2).unwrap();
+    cds_time_tests::assert_dump_cds_time("cds_time2", cds_time2, cds_time);
+    // malformed milliseconds of day
+    packet[23] = 0xff;
+    let error_message = packet.get_cds_time_acc(CDS_TIME_ACC2).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    packet[16] = cds_time::L2_TIME_2_4_2;
+    let error_message = packet.get_cds_time_acc(CDS_TIME_ACC1).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = packet.get_cds_time_acc(CDS_TIME_ACC_ERR1).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = packet.set_cds_time_acc(CDS_TIME_ACC1, cds_time).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // test CUC time access with p-field extension
+    let mut packet = c_packet::Packet::new_alloc(32);
+    let format = cuc_time::Format::new(cuc_time::TIME_CODE_ID_1958, 5, 4, true).unwrap();
+    let cuc_time = cuc_time::Time::new_from_format_init(format, &[1, 2, 3, 4, 5], &[6, 7, 8, 9]).unwrap();
+    c_packet::set_cuc_time_with_format(&mut packet, 16, &format, cuc_time).unwrap();
+    assert_dump_packet("packet", &packet, "
+0000 00 00 00 00 00 19 00 00 00 00 00 00 00 00 00 00 ................
+0010 9f 24 01 02 03 04 05 06 07 08 09 00 00 00 00 00 .$..............");
+    let cuc_time2 = c_packet::get_cuc_time_with_format(&packet, 16, &format).unwrap();
+    cuc_time_tests::assert_dump_cuc_time("cuc_time2", cuc_time2, cuc_time);
+    let cuc_time2 = c_packet::get_embedded_cuc_time(&packet, 16).unwrap();
+    cuc_time_tests::assert_dump_cuc_time("cuc_time2", cuc_time2, cuc_time);
+    let error_message = c_packet::get_cuc_time_with_format(&packet, 24, &format).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // the time must match the format of the accessor
+    let format2 = cuc_time::Format::new(cuc_time::TIME_CODE_ID_1958, 7, 10, true).unwrap();
+    let cuc_time3 = cuc_time::Time::new_from_format(format2);
+    let error = c_packet::set_cuc_time_with_format(&mut packet, 16, &format, cuc_time3).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error);
+    assert_eq!(*error.kind(), exception::ErrorKind::InvalidPField {p_field: cuc_time3.get_p_field()});
+    let error_message = c_packet::set_cuc_time(&mut packet, 16, cuc_time::L1_TIME_4_2, cuc_time::Time::new_l1_time_4_3()).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = c_packet::get_embedded_cuc_time(&packet, 24).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // test packet views on a sub-range of a frame buffer
+    let mut frame = du::DU::new_alloc(8);
+    let byte_pos = frame.append_zeros(7).unwrap();
+    {
+        let mut packet = c_packet::TMpacket::new_read_write(
+            frame.sub_buffer_mut(byte_pos..(byte_pos + 7)).unwrap());
+        packet.set_application_process_id_field(1234).unwrap();
+        packet.set_packet_length().unwrap();
+    }
+    {
+        let packet = c_packet::TMpacket::new_read_only(
+            frame.sub_buffer(byte_pos..frame.size()).unwrap());
+        let apid = packet.get_application_process_id_field().unwrap();
+        assert::dump_u32("apid", apid, 1234);
+        let offset = packet.offset_in(frame.buffer_read_only()).unwrap();
+        assert::dump_usize("offset", offset, 8);
+    }
+    assert_dump_du("frame", &frame, "
+0000 00 00 00 00 00 00 00 00 04 d2 00 00 00 00 00    ...............");
+    // test the derived packet against the hand written one
+    let mut derived_packet = DerivedTMpacket::new_alloc(18);
+    derived_packet.set_packet_type_field(c_packet::TM_PACKET_TYPE).unwrap();
+    derived_packet.set_application_process_id_field(1234).unwrap();
+    derived_packet.set_sequence_control_count_field(0x3fff).unwrap();
+    derived_packet.set_packet_length_field(11).unwrap();
+    let cuc_time = cuc_time::Time::new_t2_time_4_3_init(1, 2, 3, 4, 5, 6, 7);
+    derived_packet.set_packet_time_field(cuc_time).unwrap();
+    derived_packet[17] = 0x55;
+    assert_dump_derived_tm_packet("derived_packet", &derived_packet, "
+0000 04 d2 3f ff 00 0b 01 02 03 04 05 06 07 00 00 00 ..?.............
+0010 00 55                                           .U");
+    let packet = c_packet::TMpacket::new_read_only(derived_packet.buffer_read_only());
+    assert::dump_u32("apid", packet.get_application_process_id_field().unwrap(), 1234);
+    assert::dump_u32("ssc", packet.get_sequence_control_count_field().unwrap(), 0x3fff);
+    if !packet.check_packet_length().unwrap() {
+        panic!("inconsistent packet length");
+    }
+    let mut bytes = derived_packet.buffer_read_only().to_vec();
+    {
+        let mut derived_packet2 = DerivedTMpacket::new_read_write(&mut bytes);
+        assert::dump_u32("version_number", derived_packet2.get_version_number_field().unwrap(), 0);
+        assert::dump_u32("packet_length", derived_packet2.get_packet_length_field().unwrap(), 11);
+        let cuc_time2 = derived_packet2.get_packet_time_field().unwrap();
+        cuc_time_tests::assert_dump_cuc_time("cuc_time2", cuc_time2, cuc_time);
+        derived_packet2.set_application_process_id_field(4321 & 0x7ff).unwrap();
+        let error_message = derived_packet2.set_packet_type_field(2).
+            expect_err("expected error did not happen");
+        println!("expected: {}", error_message);
+    }
+    let derived_packet3 = DerivedTMpacket::new_owner(bytes);
+    assert::dump_u32("apid", derived_packet3.get_application_process_id_field().unwrap(), 4321 & 0x7ff);
+    let derived_packet4 = DerivedTMpacket::new_alloc(8);
+    let error_message = derived_packet4.get_packet_time_field().
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // annotated dump of the primary header
+    let mut tm_packet = c_packet::TMpacket::new_alloc(12);
+    tm_packet.set_application_process_id_field(1234).unwrap();
+    tm_packet.set_segmentation_flags_field(c_packet::SEGMENTATION_NONE).unwrap();
+    tm_packet.set_sequence_control_count_field(17).unwrap();
+    tm_packet.set_checksum().unwrap();
+    let dump = tm_packet.dump_annotated_str();
+    println!("tm_packet = {}", dump);
+    assert_eq!(dump, "
+version number         = 0
+packet type            = 0 (TM)
+data field header flag = 0
+application process id = 1234
+segmentation flags     = 3 (none)
+sequence control count = 17
+packet length          = 5 (consistent)
+checksum               = ok
+0000 04 d2 c0 11 00 05 00 00 00 00 9e e2             ............");
+    // selectable packet error control
+    let error_controls = [
+        c_packet::ErrorControl::Crc16,
+        c_packet::ErrorControl::IsoChecksum,
+        c_packet::ErrorControl::Crc32Ieee,
+        c_packet::ErrorControl::Crc32Ccsds];
+    for error_control in error_controls.iter() {
+        tm_packet.set_checksum_with(*error_control).unwrap();
+        println!("{:?} = {}", error_control, tm_packet.dump_str());
+        if !tm_packet.check_checksum_with(*error_control).unwrap() {
+            panic!("invalid {:?} error control", error_control);
+        }
+    }
+    assert::dump_string("tm_packet", &tm_packet.dump_str(), "
+0000 04 d2 c0 11 00 05 00 00 7b ed bb df             ........{...");
+    // the CRC-32 overwrites the data field
+    tm_packet[8] = 0;
+    tm_packet[9] = 0;
+    tm_packet.set_checksum().unwrap();
+    // checksum over a packet in segments
+    let bytes = tm_packet.buffer_read_only();
+    assert::dump_u16("checksum", c_packet::calculate_checksum(&[&bytes[..4], &[], &bytes[4..10]]), 0x9ee2);
+    let mut last_segment = c_packet::Packet::new_owner(bytes[6..].to_vec());
+    last_segment.set_unsigned(4, 2, 0).unwrap();
+    last_segment.set_checksum_segmented(&[&bytes[..4], &bytes[4..6]]).unwrap();
+    assert::dump_string("last_segment", &last_segment.dump_str(), "
+0000 00 00 00 00 9e e2                               ......");
+    tm_packet.verify_checksum_with(c_packet::ErrorControl::Crc16).unwrap();
+    tm_packet[6] = 1;
+    let error = tm_packet.verify_checksum_with(c_packet::ErrorControl::Crc16).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error);
+    if let exception::ErrorKind::ChecksumMismatch {..} = *error.kind() {} else {
+        panic!("unexpected error kind {:?}", error.kind());
+    }
+    let dump = tm_packet.dump_annotated_str();
+    println!("tm_packet = {}", dump);
+    assert!(dump.contains("checksum               = invalid"));
+    let short_packet = c_packet::Packet::new_owner(vec![0x08, 0x00]);
+    short_packet.dump_annotated("short_packet");
+    // structured error kinds
+    let error = cuc_time::Time::new_from_p_field(0).expect_err("expected error did not happen");
+    assert_eq!(*error.kind(), exception::ErrorKind::InvalidPField {p_field: 0});
+    let error = c_packet::get_cuc_time(&short_packet, 0, cuc_time::T1_TIME_4_0).
+        expect_err("expected error did not happen");
+    assert_eq!(*error.kind(), exception::ErrorKind::OutOfBounds {pos: 0, length: 4, size: 2});
+    tm_packet.set_packet_length_field(4).unwrap();
+    let error = tm_packet.set_checksum().expect_err("expected error did not happen");
+    assert_eq!(*error.kind(), exception::ErrorKind::LengthMismatch {expected: 11, actual: 12});
+    // the packet length field limits the packet size
+    let mut large_packet = c_packet::Packet::new_owner(vec![0; 65543]);
+    let error = large_packet.set_packet_length().expect_err("expected error did not happen");
+    println!("expected: {}", error);
+    assert_eq!(*error.kind(), exception::ErrorKind::ValueOutOfRange {value: 65543, min: 7, max: 65542});
+    large_packet.truncate(65542).unwrap();
+    large_packet.set_packet_length().unwrap();
+    assert::dump_u32("packet length", large_packet.get_packet_length_field().unwrap(), 0xFFFF);
+    // structured validation report
+    tm_packet.set_packet_length().unwrap();
+    tm_packet.set_checksum().unwrap();
+    let report = tm_packet.validate();
+    println!("report = {}", report);
+    assert!(report.is_valid());
+    tm_packet.set_version_number_field(1).unwrap();
+    tm_packet.set_segmentation_flags_field(c_packet::SEGMENTATION_FIRST).unwrap();
+    tm_packet.set_packet_length_field(7).unwrap();
+    let options = c_packet::ValidationOptions {
+        error_control: c_packet::ErrorControl::Crc16,
+        require_unsegmented: true
+    };
+    let report = tm_packet.validate_with(&options);
+    println!("report = {}", report);
+    assert_eq!(report.issues, vec![
+        c_packet::ValidationIssue::InvalidVersionNumber {version_number: 1},
+        c_packet::ValidationIssue::InvalidSegmentationFlags {segmentation_flags: c_packet::SEGMENTATION_FIRST},
+        c_packet::ValidationIssue::LengthMismatch {expected_size: 14, actual_size: 12},
+        c_packet::ValidationIssue::ChecksumMismatch {expected: 0xe856, computed: 0x6859}]);
+    let report = short_packet.validate();
+    println!("report = {}", report);
+    assert_eq!(report.issues, vec![c_packet::ValidationIssue::IncompleteHeader {size: 2}]);
+    // a packet with only the primary header has no error control
+    let mut header_packet = c_packet::Packet::new_owner(vec![0; c_packet::PRIMARY_HEADER_BYTE_SIZE]);
+    assert!(!header_packet.check_packet_length().unwrap());
+    assert!(!header_packet.check_checksum().unwrap());
+    let error = header_packet.set_checksum().expect_err("expected error did not happen");
+    assert_eq!(*error.kind(), exception::ErrorKind::LengthMismatch {expected: 7, actual: 6});
+    let report = header_packet.validate_with(&c_packet::ValidationOptions {
+        error_control: c_packet::ErrorControl::Crc32Ieee,
+        require_unsegmented: false
+    });
+    println!("report = {}", report);
+    assert_eq!(report.issues, vec![
+        c_packet::ValidationIssue::LengthMismatch {expected_size: 7, actual_size: 6},
+        c_packet::ValidationIssue::MissingErrorControl {size: 6, error_control: c_packet::ErrorControl::Crc32Ieee}]);
 }
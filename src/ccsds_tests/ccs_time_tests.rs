@@ -0,0 +1,120 @@
+//*****************************************************************************
+// (C) 2018, Stefan Korner, Austria                                           *
+//                                                                            *
+// The Space Rust Library is free software; you can redistribute it and/or    *
+// modify it under the terms of the MIT License as published by the           *
+// Massachusetts Institute of Technology.                                     *
+//                                                                            *
+// The Space Rust Library is distributed in the hope that it will be useful,  *
+// but WITHOUT ANY WARRANTY; without even the implied warranty of             *
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the MIT License   *
+// for more details.                                                          *
+//*****************************************************************************
+// CCS TIME - CCSDS calendar segmented time code - Unit tests                 *
+//*****************************************************************************
+use ccsds::ccs_time;
+use test_helpers::assert;
+use time;
+use util::exception;
+
+///////////////
+// functions //
+///////////////
+
+fn test_timespec_ok(
+    p_field: u8,
+    timespec: time::Timespec,
+    expected_dump: &str,
+    expected_bytes: &[u8],
+    expected_timespec_nsec: i32) {
+    let ccs_time = ccs_time::Time::new_from_timespec(p_field, timespec).unwrap();
+    assert::dump_string("ccs time", &ccs_time.dump_str(), expected_dump);
+    assert::dump_timespec("ccs time", ccs_time.to_timespec(), timespec.sec, expected_timespec_nsec);
+    // encoding and decoding of bytes
+    let mut bytes = vec![0; expected_bytes.len()];
+    ccs_time.update_to_bytes(&mut bytes);
+    println!("bytes = {:?}", bytes);
+    assert_eq!(&bytes[..], expected_bytes);
+    let mut ccs_time2 = ccs_time::Time::new_from_p_field(p_field).unwrap();
+    ccs_time2.init_from_bytes(&bytes).unwrap();
+    assert::dump_string("ccs time 2", &ccs_time2.dump_str(), expected_dump);
+}
+
+fn test_bytes_error(bytes: &[u8]) {
+    let error_message = ccs_time::Time::new_from_bytes(bytes).
+        expect_err("expected error did not happen");
+    println!("expected: {} for {:?}", error_message, bytes);
+}
+
+pub fn test() {
+    let test_time = time::Timespec::new(315968523, 123456789);
+    // p-field handling
+    assert::dump_usize("full data size", ccs_time::get_full_data_size(ccs_time::L_MONTH_DAY).unwrap(), 8);
+    assert::dump_usize("full data size", ccs_time::get_full_data_size(ccs_time::L_DAY_OF_YEAR | 6).unwrap(), 14);
+    assert::dump_usize("full data size", ccs_time::get_full_data_size(ccs_time::T_MONTH_DAY | 2).unwrap(), 9);
+    assert!(ccs_time::has_p_field(ccs_time::L_DAY_OF_YEAR));
+    assert!(!ccs_time::has_p_field(ccs_time::T_DAY_OF_YEAR));
+    assert!(!ccs_time::has_p_field(ccs_time::L_MONTH_DAY | 7));
+    let error_message = ccs_time::Time::new_from_p_field(ccs_time::L_MONTH_DAY | 7).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = ccs_time::Time::new_from_p_field(0x40).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // conversion from/to timespec and bytes
+    test_timespec_ok(ccs_time::L_MONTH_DAY | 3, test_time,
+        "1980-01-06T01:02:03.123456",
+        &[0x53, 0x19, 0x80, 0x01, 0x06, 0x01, 0x02, 0x03, 0x12, 0x34, 0x56],
+        123456000);
+    test_timespec_ok(ccs_time::L_DAY_OF_YEAR | 6, test_time,
+        "1980-006T01:02:03.123456789000",
+        &[0x5e, 0x19, 0x80, 0x00, 0x06, 0x01, 0x02, 0x03, 0x12, 0x34, 0x56, 0x78, 0x90, 0x00],
+        123456789);
+    test_timespec_ok(ccs_time::T_DAY_OF_YEAR, test_time,
+        "1980-006T01:02:03",
+        &[0x19, 0x80, 0x00, 0x06, 0x01, 0x02, 0x03],
+        0);
+    test_timespec_ok(ccs_time::T_MONTH_DAY | 1, time::Timespec::new(1483228799, 990000000),
+        "2016-12-31T23:59:59.99",
+        &[0x20, 0x16, 0x12, 0x31, 0x23, 0x59, 0x59, 0x99],
+        990000000);
+    let error_message = ccs_time::Time::new_from_timespec(ccs_time::L_MONTH_DAY, time::Timespec::new(253402300800, 0)).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // day of year and month/day of month are derived from each other
+    let ccs_time = ccs_time::Time::new_from_bytes(&[0x58, 0x20, 0x16, 0x03, 0x66, 0x00, 0x00, 0x00]).unwrap();
+    assert::dump_u8("month", ccs_time.get_month(), 12);
+    assert::dump_u8("day of month", ccs_time.get_day_of_month(), 31);
+    let ccs_time = ccs_time::Time::new_from_bytes(&[0x50, 0x20, 0x16, 0x03, 0x01, 0x00, 0x00, 0x00]).unwrap();
+    assert_eq!(ccs_time.get_day_of_year(), 61);
+    test_bytes_error(&[]);
+    test_bytes_error(&[0xd0, 0x20, 0x16, 0x03, 0x01, 0x00, 0x00, 0x00]);
+    test_bytes_error(&[0x50, 0x20, 0x16, 0x03, 0x01, 0x00, 0x00]);
+    test_bytes_error(&[0x50, 0x20, 0x1a, 0x03, 0x01, 0x00, 0x00, 0x00]);
+    test_bytes_error(&[0x50, 0x20, 0x16, 0x13, 0x01, 0x00, 0x00, 0x00]);
+    test_bytes_error(&[0x50, 0x20, 0x15, 0x02, 0x29, 0x00, 0x00, 0x00]);
+    test_bytes_error(&[0x58, 0x20, 0x15, 0x03, 0x66, 0x00, 0x00, 0x00]);
+    test_bytes_error(&[0x58, 0x20, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    // invalid BCD octets are malformed input
+    let error = ccs_time::Time::new_from_bytes(&[0x50, 0x20, 0x1a, 0x03, 0x01, 0x00, 0x00, 0x00]).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error);
+    assert_eq!(*error.kind(), exception::ErrorKind::ParseError {input: String::from("1a")});
+    // non-existing dates report the valid range
+    let error = ccs_time::Time::new_from_bytes(&[0x50, 0x20, 0x15, 0x02, 0x29, 0x00, 0x00, 0x00]).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error);
+    assert_eq!(*error.kind(), exception::ErrorKind::ValueOutOfRange {value: 29, min: 1, max: 28});
+    let error = ccs_time::Time::new_from_bytes(&[0x58, 0x20, 0x15, 0x03, 0x66, 0x00, 0x00, 0x00]).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error);
+    assert_eq!(*error.kind(), exception::ErrorKind::ValueOutOfRange {value: 366, min: 1, max: 365});
+    test_bytes_error(&[0x50, 0x20, 0x16, 0x03, 0x01, 0x24, 0x00, 0x00]);
+    test_bytes_error(&[0x50, 0x20, 0x16, 0x03, 0x01, 0x00, 0x60, 0x00]);
+    // conversion from/to ASD time
+    let ccs_time = ccs_time::Time::new_from_timespec(ccs_time::L_MONTH_DAY | 1, test_time).unwrap();
+    assert::dump_string("ASD time", &ccs_time.get_asd_time_str(), "1980.006.01.02.03.120");
+    let ccs_time = ccs_time::Time::new_from_asd_time_str(ccs_time::L_DAY_OF_YEAR | 2, "1980.006.01.02.03.1234").unwrap();
+    assert::dump_string("ccs time", &ccs_time.dump_str(), "1980-006T01:02:03.1234");
+    assert::dump_string("ASD time", &ccs_time.get_asd_time_str(), "1980.006.01.02.03.123400");
+}
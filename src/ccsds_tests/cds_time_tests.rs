@@ -0,0 +1,134 @@
+//*****************************************************************************
+// (C) 2018, Stefan Korner, Austria                                           *
+//                                                                            *
+// The Space Rust Library is free software; you can redistribute it and/or    *
+// modify it under the terms of the MIT License as published by the           *
+// Massachusetts Institute of Technology.                                     *
+//                                                                            *
+// The Space Rust Library is distributed in the hope that it will be useful,  *
+// but WITHOUT ANY WARRANTY; without even the implied warranty of             *
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the MIT License   *
+// for more details.                                                          *
+//*****************************************************************************
+// CDS TIME - CCSDS day segmented time code - Unit tests                      *
+//*****************************************************************************
+use ccsds::cds_time;
+use test_helpers::assert;
+use time;
+
+///////////////
+// functions //
+///////////////
+
+pub fn assert_dump_cds_time(
+    val_name: &str,
+    val: cds_time::Time,
+    expected: cds_time::Time) {
+    println!("{} = {}", val_name, val);
+    assert_eq!(val.dump_str(), expected.dump_str());
+}
+
+fn test_new_from_timespec_error(p_field: u8, timespec: time::Timespec) {
+    let error_message = cds_time::Time::new_from_timespec(p_field, timespec).
+        expect_err("expected error did not happen");
+    println!("expected: {} for {},{}", error_message, timespec.sec, timespec.nsec);
+}
+
+fn test_new_from_timespec_ok(
+    timespec: time::Timespec,
+    expected: cds_time::Time,
+    expected_timespec_nsec: i32) {
+    let p_field = expected.get_p_field();
+    let cds_time = cds_time::Time::new_from_timespec(p_field, timespec).unwrap();
+    assert_dump_cds_time(
+        &format!("({},{})", timespec.sec, timespec.nsec), cds_time, expected);
+    assert::dump_timespec(
+        "cds time",
+        cds_time.to_timespec(),
+        timespec.sec,
+        expected_timespec_nsec);
+}
+
+fn test_bytes(
+    cds_time: cds_time::Time,
+    expected_bytes: &[u8]) {
+    let mut bytes = vec![0; expected_bytes.len()];
+    cds_time.update_to_bytes(&mut bytes);
+    println!("bytes = {:?}", bytes);
+    assert_eq!(&bytes[..], expected_bytes);
+    let mut cds_time2 = cds_time::Time::new_from_p_field(cds_time.get_p_field()).unwrap();
+    cds_time2.init_from_bytes(&bytes).unwrap();
+    assert_dump_cds_time("cds time 2", cds_time2, cds_time);
+}
+
+pub fn test() {
+    let xtime = cds_time::Time::new_from_p_field(cds_time::L1_TIME_2_4_2).unwrap();
+    println!("xtime = {}", xtime);
+    // p-field handling
+    assert::dump_usize("full data size", cds_time::get_full_data_size(cds_time::L1_TIME_2_4_0).unwrap(), 7);
+    assert::dump_usize("full data size", cds_time::get_full_data_size(cds_time::L2_TIME_3_4_4).unwrap(), 12);
+    assert::dump_usize("full data size", cds_time::get_full_data_size(cds_time::T1_TIME_2_4_2).unwrap(), 8);
+    assert::dump_usize("data size", cds_time::get_data_size(cds_time::T2_TIME_3_4_2).unwrap(), 9);
+    assert!(cds_time::has_p_field(cds_time::L2_TIME_2_4_0));
+    assert!(!cds_time::has_p_field(cds_time::T2_TIME_2_4_0));
+    assert!(!cds_time::has_p_field(0x43));
+    let error_message = cds_time::get_data_size(0x43).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = cds_time::Time::new_from_p_field(0x1c).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // init constructor
+    let error_message = cds_time::Time::new_init(cds_time::L1_TIME_2_4_0, 0x10000, 0, 0).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = cds_time::Time::new_init(cds_time::L1_TIME_2_4_0, 0, 86401000, 0).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = cds_time::Time::new_init(cds_time::L1_TIME_2_4_2, 0, 0, 1000).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let cds_time = cds_time::Time::new_init(cds_time::L1_TIME_3_4_0, 0x10000, 86400999, 0).unwrap();
+    assert::dump_string("cds time", &cds_time.dump_str(), "L1Time340(65536,86400999)");
+    // conversion from/to timespec
+    test_new_from_timespec_error(cds_time::L1_TIME_2_4_0, time::Timespec::new(-1, 0));
+    test_new_from_timespec_error(cds_time::L1_TIME_2_4_0, time::Timespec::new(0x10000 * 86400, 0));
+    test_new_from_timespec_ok(
+        time::Timespec::new(0xFFFF * 86400 + 86399, 0),
+        cds_time::Time::new_init(cds_time::L1_TIME_2_4_0, 0xFFFF, 86399000, 0).unwrap(),
+        0);
+    test_new_from_timespec_ok(
+        time::Timespec::new(3 * 86400 + 5, 123456789),
+        cds_time::Time::new_init(cds_time::L1_TIME_2_4_0, 3, 5123, 0).unwrap(),
+        123000000);
+    test_new_from_timespec_ok(
+        time::Timespec::new(3 * 86400 + 5, 123456789),
+        cds_time::Time::new_init(cds_time::L2_TIME_2_4_2, 3, 5123, 456).unwrap(),
+        123456000);
+    test_new_from_timespec_ok(
+        time::Timespec::new(3 * 86400 + 5, 123456789),
+        cds_time::Time::new_init(cds_time::T1_TIME_3_4_4, 3, 5123, 456789000).unwrap(),
+        123456789);
+    // encoding and decoding of bytes
+    test_bytes(
+        cds_time::Time::new_init(cds_time::L1_TIME_2_4_2, 3, 5123, 456).unwrap(),
+        &[0x41, 0x00, 0x03, 0x00, 0x00, 0x14, 0x03, 0x01, 0xc8]);
+    test_bytes(
+        cds_time::Time::new_init(cds_time::T2_TIME_3_4_4, 0x010203, 0x04050607, 0x08090a0b).unwrap(),
+        &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b]);
+    let cds_time = cds_time::Time::new_from_bytes(&[0x4c, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07]).unwrap();
+    assert::dump_string("cds time", &cds_time.dump_str(), "L2Time340(66051,67438087)");
+    let error_message = cds_time::Time::new_from_bytes(&[0x4c, 0x01, 0x02]).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = cds_time::Time::new_from_bytes(&[0xcc, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06]).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // decoded segments out of range
+    let error_message = cds_time::Time::new_from_bytes(&[0x41, 0x00, 0x00, 0x00, 0x00, 0x03, 0xe7, 0xff, 0xff]).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = cds_time::Time::new_from_bytes(&[0x40, 0x00, 0x00, 0x05, 0x26, 0x5f, 0xe8]).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+}
@@ -13,8 +13,10 @@
 // CUC TIME - CCSDS unsegmented time code - Unit tests                        *
 //*****************************************************************************
 use ccsds::cuc_time;
+use std::collections::HashSet;
 use test_helpers::assert;
 use time;
+use util::exception;
 
 ///////////////
 // functions //
@@ -55,6 +57,53 @@ fn test_to_timespec(
         expected_timespec_nsec);
 }
 
+fn test_convert_ok(
+    cuc_time: cuc_time::Time,
+    p_field: u8,
+    rounding: cuc_time::RoundingMode,
+    expected: cuc_time::Time) {
+    let converted = cuc_time.convert(p_field, rounding).unwrap();
+    assert_dump_cuc_time(&format!("{} {:?}", cuc_time, rounding), converted, expected);
+}
+
+fn test_format_from_bytes_ok(
+    bytes: &[u8],
+    expected_name: &str,
+    expected_full_data_size: usize) {
+    let format = cuc_time::Format::new_from_bytes(bytes).unwrap();
+    assert::dump_string("format", &format.get_name(), expected_name);
+    assert::dump_usize("full data size", format.get_full_data_size(), expected_full_data_size);
+    // encoding of the p-field is the reverse of the parsing
+    let p_field_octets = format.get_p_field_octets();
+    assert_eq!(&p_field_octets[..], &bytes[..format.get_p_field_size()]);
+}
+
+fn test_format_from_bytes_error(bytes: &[u8]) {
+    let error_message = cuc_time::Format::new_from_bytes(bytes).
+        expect_err("expected error did not happen");
+    println!("expected: {} for {:?}", error_message, bytes);
+}
+
+fn test_generic_timespec(
+    format: cuc_time::Format,
+    timespec: time::Timespec,
+    expected_dump: &str) {
+    let mut cuc_time = cuc_time::Time::new_from_format(format);
+    cuc_time.init_from_timespec(timespec).unwrap();
+    assert::dump_string("cuc time", &cuc_time.dump_str(), expected_dump);
+    test_to_timespec(cuc_time, timespec.sec, timespec.nsec);
+    // encoding and parsing of the bytes
+    let mut bytes = vec![0; format.get_full_data_size()];
+    cuc_time.update_to_bytes(&mut bytes);
+    let mut cuc_time2 = cuc_time::Time::new_from_format(format);
+    cuc_time2.init_from_bytes(&bytes);
+    assert_dump_cuc_time("cuc time 2", cuc_time2, cuc_time);
+    if format.embedded_p_field {
+        let cuc_time3 = cuc_time::Time::new_from_bytes(&bytes).unwrap();
+        assert_dump_cuc_time("cuc time 3", cuc_time3, cuc_time);
+    }
+}
+
 pub fn test() {
     let xtime = cuc_time::Time::new_l2_time_4_3();
     println!("xtime = {}", xtime);
@@ -130,4 +179,237 @@ pub fn test() {
     test_to_timespec(
         cuc_time::Time::new_l2_time_4_3_init(1, 2, 3, 4, 5, 6, 7),
         0x01020304, 19623219);
+    // single octet p-fields with other sizes
+    assert::dump_usize("full data size 2+2", cuc_time::get_full_data_size(0x16).unwrap(), 5);
+    assert::dump_usize("full data size 3+2", cuc_time::get_full_data_size(0x2a).unwrap(), 6);
+    assert::dump_usize("data size 1+0", cuc_time::get_data_size(0xa0).unwrap(), 1);
+    assert!(cuc_time::has_p_field(0x16));
+    assert!(!cuc_time::has_p_field(0x96));
+    assert!(!cuc_time::has_p_field(0x4c));
+    test_new_from_timespec_error(0x4c, time::Timespec::new(0, 0));
+    test_new_from_timespec_error(0x2a, time::Timespec::new(0x1000000, 0));
+    let cuc_time = cuc_time::Time::new_from_p_field(0x16).unwrap();
+    assert::dump_string("cuc time 2+2", &cuc_time.dump_str(), "L1Time22((0,0),(0,0))");
+    assert::dump_u8("p-field 2+2", cuc_time.get_p_field(), 0x16);
+    // the fixed size variants are used when possible
+    let format = cuc_time::Format::new(cuc_time::TIME_CODE_ID_AGENCY, 4, 3, true).unwrap();
+    assert_dump_cuc_time("cuc time 4+3", cuc_time::Time::new_from_format(format),
+        cuc_time::Time::new_l2_time_4_3());
+    assert_eq!(cuc_time::Time::new_t1_time_4_2().get_format(),
+        cuc_time::Format::new(cuc_time::TIME_CODE_ID_1958, 4, 2, false).unwrap());
+    // format consistency
+    let error_message = cuc_time::Format::new(3, 4, 0, true).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = cuc_time::Format::new(cuc_time::TIME_CODE_ID_1958, 8, 0, true).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = cuc_time::Format::new(cuc_time::TIME_CODE_ID_1958, 1, 11, true).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // embedded p-fields incl. extension octet
+    test_format_from_bytes_ok(&[0x16], "L1Time22", 5);
+    test_format_from_bytes_ok(&[0x2a], "L2Time32", 6);
+    test_format_from_bytes_ok(&[0x9f, 0x20], "L1Time53", 10);
+    test_format_from_bytes_ok(&[0x9f, 0x7c], "L1Time710", 19);
+    test_format_from_bytes_ok(&[0xaf, 0x04], "L2Time44", 10);
+    test_format_from_bytes_error(&[]);
+    test_format_from_bytes_error(&[0x9f]);
+    test_format_from_bytes_error(&[0x9f, 0x80]);
+    test_format_from_bytes_error(&[0x3f]);
+    let error_message = cuc_time::Time::new_from_bytes(&[0x16, 1, 2, 3]).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // conversion from/to timespec
+    let format = cuc_time::Format::new(cuc_time::TIME_CODE_ID_1958, 2, 2, true).unwrap();
+    test_generic_timespec(format, time::Timespec::new(0x0102, 500000000),
+        "L1Time22((1,2),(128,0))");
+    let format = cuc_time::Format::new(cuc_time::TIME_CODE_ID_AGENCY, 3, 2, false).unwrap();
+    test_generic_timespec(format, time::Timespec::new(0x010203, 250000000),
+        "T2Time32((1,2,3),(64,0))");
+    let format = cuc_time::Format::new(cuc_time::TIME_CODE_ID_1958, 7, 10, true).unwrap();
+    test_generic_timespec(format, time::Timespec::new(0x01020304050607, 19531250),
+        "L1Time710((1,2,3,4,5,6,7),(5,0,0,0,0,0,0,0,0,0))");
+    let cuc_time = cuc_time::Time::new_from_format_init(format,
+        &[0, 0, 0, 0, 0, 0, 1], &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+    test_to_timespec(cuc_time, 1, 0);
+    let error_message = cuc_time::Time::new_from_format_init(format, &[0, 1], &[]).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // comparison across variants
+    let time1 = cuc_time::Time::new_l1_time_4_3_init(1, 2, 3, 4, 128, 0, 0);
+    let time2 = cuc_time::Time::new_t2_time_4_1_init(1, 2, 3, 4, 128);
+    let time3 = cuc_time::Time::new_l2_time_4_2_init(1, 2, 3, 4, 128, 1);
+    let time4 = cuc_time::Time::new_t1_time_4_0_init(1, 2, 3, 5);
+    assert_eq!(time1, time2);
+    assert!(time1 != time3);
+    assert!(time2 < time3);
+    assert!(time3 < time4);
+    let mut times = vec![time4, time3, time2];
+    times.sort();
+    assert_eq!(times, vec![time1, time3, time4]);
+    let mut time_set = HashSet::new();
+    time_set.insert(time1);
+    time_set.insert(time2);
+    time_set.insert(time3);
+    assert::dump_usize("time set", time_set.len(), 2);
+    // arithmetic with durations
+    let time = cuc_time::Time::new_l1_time_4_0_init(0, 0, 0, 1);
+    let duration = time::Duration::milliseconds(1500);
+    assert_dump_cuc_time("time + 1.5s",
+        time.add_duration(duration, cuc_time::RoundingMode::Truncate).unwrap(),
+        cuc_time::Time::new_l1_time_4_0_init(0, 0, 0, 2));
+    assert_dump_cuc_time("time + 1.5s",
+        time.add_duration(duration, cuc_time::RoundingMode::Nearest).unwrap(),
+        cuc_time::Time::new_l1_time_4_0_init(0, 0, 0, 3));
+    assert_dump_cuc_time("time - 0.5s",
+        time.sub_duration(time::Duration::milliseconds(500), cuc_time::RoundingMode::Truncate).unwrap(),
+        cuc_time::Time::new_l1_time_4_0_init(0, 0, 0, 0));
+    let time = cuc_time::Time::new_l2_time_4_3_init(0, 0, 0, 1, 0, 0, 0);
+    assert_dump_cuc_time("time + 1/256s",
+        time.add_duration(time::Duration::nanoseconds(3906250), cuc_time::RoundingMode::Truncate).unwrap(),
+        cuc_time::Time::new_l2_time_4_3_init(0, 0, 0, 1, 1, 0, 0));
+    assert_dump_cuc_time("time - 1/256s",
+        time.sub_duration(time::Duration::nanoseconds(3906250), cuc_time::RoundingMode::Truncate).unwrap(),
+        cuc_time::Time::new_l2_time_4_3_init(0, 0, 0, 0, 255, 0, 0));
+    assert_dump_cuc_time("time + 1ns",
+        time.add_duration(time::Duration::nanoseconds(1), cuc_time::RoundingMode::Ceil).unwrap(),
+        cuc_time::Time::new_l2_time_4_3_init(0, 0, 0, 1, 0, 0, 1));
+    let error_message = cuc_time::Time::new_t1_time_4_0_init(255, 255, 255, 255).
+        add_duration(time::Duration::seconds(1), cuc_time::RoundingMode::Truncate).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = cuc_time::Time::new_t1_time_4_1_init(0, 0, 0, 0, 1).
+        sub_duration(time::Duration::nanoseconds(3906251), cuc_time::RoundingMode::Truncate).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // difference between two times
+    let difference = time4 - time3;
+    assert_eq!(difference.num_nanoseconds(), Some(499984741));
+    let difference = time3.difference(&time4);
+    assert_eq!(difference.num_nanoseconds(), Some(-499984742));
+    assert_eq!((time1 - time2).num_nanoseconds(), Some(0));
+    // conversion between variants
+    let time = cuc_time::Time::new_l1_time_4_3_init(0, 0, 0, 1, 128, 128, 128);
+    test_convert_ok(time, cuc_time::T2_TIME_4_2, cuc_time::RoundingMode::Truncate,
+        cuc_time::Time::new_t2_time_4_2_init(0, 0, 0, 1, 128, 128));
+    test_convert_ok(time, cuc_time::T2_TIME_4_2, cuc_time::RoundingMode::Nearest,
+        cuc_time::Time::new_t2_time_4_2_init(0, 0, 0, 1, 128, 129));
+    test_convert_ok(time, cuc_time::L1_TIME_4_0, cuc_time::RoundingMode::Truncate,
+        cuc_time::Time::new_l1_time_4_0_init(0, 0, 0, 1));
+    test_convert_ok(time, cuc_time::L1_TIME_4_0, cuc_time::RoundingMode::Nearest,
+        cuc_time::Time::new_l1_time_4_0_init(0, 0, 0, 2));
+    test_convert_ok(time, cuc_time::L2_TIME_4_3, cuc_time::RoundingMode::Ceil,
+        cuc_time::Time::new_l2_time_4_3_init(0, 0, 0, 1, 128, 128, 128));
+    test_convert_ok(cuc_time::Time::new_t1_time_4_1_init(0, 0, 0, 1, 1), cuc_time::L1_TIME_4_0,
+        cuc_time::RoundingMode::Ceil, cuc_time::Time::new_l1_time_4_0_init(0, 0, 0, 2));
+    let format = cuc_time::Format::new(cuc_time::TIME_CODE_ID_1958, 2, 2, true).unwrap();
+    let converted = time.convert_to_format(format, cuc_time::RoundingMode::Nearest).unwrap();
+    assert::dump_string("converted", &converted.dump_str(), "L1Time22((0,1),(128,129))");
+    let error_message = time4.convert_to_format(format, cuc_time::RoundingMode::Nearest).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = cuc_time::Time::new_l1_time_4_1_init(255, 255, 255, 255, 255).
+        convert(cuc_time::L1_TIME_4_0, cuc_time::RoundingMode::Nearest).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // exact fine time conversion with rounding
+    let fine_time = cuc_time::FineTime3::new_init(0, 0, 1);
+    assert_eq!(fine_time.to_nsec(), 59);
+    assert_eq!(fine_time.to_nsec_with(cuc_time::RoundingMode::Nearest), 60);
+    assert_eq!(fine_time.to_nsec_with(cuc_time::RoundingMode::Ceil), 60);
+    assert_eq!(fine_time.to_nsec_with_remainder(), (59, 10144256));
+    let mut fine_time = cuc_time::FineTime2::new();
+    fine_time.init_from_nsec_with(15259, cuc_time::RoundingMode::Nearest).unwrap();
+    assert_eq!((fine_time.t_fine0, fine_time.t_fine1), (0, 1));
+    fine_time.init_from_nsec_with(15258, cuc_time::RoundingMode::Truncate).unwrap();
+    assert_eq!((fine_time.t_fine0, fine_time.t_fine1), (0, 0));
+    let error = cuc_time::FineTime1::new().
+        init_from_nsec_with(999999999, cuc_time::RoundingMode::Nearest).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error);
+    assert_eq!(*error.kind(), exception::ErrorKind::ValueOutOfRange {value: 256, min: 0, max: 255});
+    let error_message = cuc_time::FineTime1::new().init_from_nsec(1000000000).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // rounding of a timespec can carry into the coarse time
+    let timespec = time::Timespec::new(0, 999999999);
+    let time = cuc_time::Time::new_from_timespec_with(cuc_time::L1_TIME_4_1, timespec,
+        cuc_time::RoundingMode::Truncate).unwrap();
+    assert_dump_cuc_time("truncated", time, cuc_time::Time::new_l1_time_4_1_init(0, 0, 0, 0, 255));
+    let time = cuc_time::Time::new_from_timespec_with(cuc_time::L1_TIME_4_1, timespec,
+        cuc_time::RoundingMode::Nearest).unwrap();
+    assert_dump_cuc_time("nearest", time, cuc_time::Time::new_l1_time_4_1_init(0, 0, 0, 1, 0));
+    let error_message = cuc_time::Time::new_from_timespec_with(cuc_time::L1_TIME_4_1,
+        time::Timespec::new(0xFFFFFFFF, 999999999), cuc_time::RoundingMode::Ceil).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let time = cuc_time::Time::new_l1_time_4_3_init(0, 0, 0, 0, 255, 255, 255);
+    let timespec = time.to_timespec_with(cuc_time::RoundingMode::Ceil);
+    assert::dump_timespec("ceil", timespec, 0, 999999941);
+    let format = cuc_time::Format::new(cuc_time::TIME_CODE_ID_1958, 1, 10, true).unwrap();
+    let time = cuc_time::Time::new_from_format_init(format, &[0], &[255; 10]).unwrap();
+    let timespec = time.to_timespec_with(cuc_time::RoundingMode::Nearest);
+    assert::dump_timespec("nearest", timespec, 1, 0);
+    let time = cuc_time::Time::new_l1_time_4_3_init(0, 0, 0, 2, 0, 0, 1);
+    let (timespec, remainder) = time.to_timespec_with_remainder();
+    assert::dump_timespec("truncated", timespec, 2, 59);
+    assert_eq!(remainder, 10144256);
+}
+
+// property tests, need the proptest crate (dev-dependency)
+#[cfg(test)]
+pub mod properties {
+    use ccsds::cuc_time;
+    use proptest::prelude::*;
+    use time;
+
+    // time with 4 coarse bytes and 1 to 3 fine bytes
+    fn new_time(fine_octets: usize, t_fine: u32) -> cuc_time::Time {
+        let format = cuc_time::Format::new(cuc_time::TIME_CODE_ID_1958, 4, fine_octets, true).unwrap();
+        let bytes = t_fine.to_be_bytes();
+        cuc_time::Time::new_from_format_init(format, &[0, 0, 0, 1], &bytes[(4 - fine_octets)..]).unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn fine_time_round_trip(fine_octets in 1_usize..=3, t_fine in any::<u32>()) {
+            let time = new_time(fine_octets, t_fine & ((1 << (8 * fine_octets)) - 1));
+            let p_field = time.get_p_field();
+            for &(rounding1, rounding2) in [
+                (cuc_time::RoundingMode::Nearest, cuc_time::RoundingMode::Nearest),
+                (cuc_time::RoundingMode::Truncate, cuc_time::RoundingMode::Ceil),
+                (cuc_time::RoundingMode::Ceil, cuc_time::RoundingMode::Truncate)].iter() {
+                let timespec = time.to_timespec_with(rounding1);
+                let time2 = cuc_time::Time::new_from_timespec_with(p_field, timespec, rounding2).unwrap();
+                prop_assert_eq!(time2.dump_str(), time.dump_str());
+            }
+        }
+
+        #[test]
+        fn nsec_round_trip(fine_octets in 1_usize..=10, nsec in 0_i32..1000000000) {
+            let format = cuc_time::Format::new(cuc_time::TIME_CODE_ID_1958, 4, fine_octets, true).unwrap();
+            let timespec = time::Timespec::new(1, nsec);
+            let mut time = cuc_time::Time::new_from_format(format);
+            time.init_from_timespec_with(timespec, cuc_time::RoundingMode::Nearest).unwrap();
+            let timespec2 = time.to_timespec_with(cuc_time::RoundingMode::Nearest);
+            // the deviation is at most half a fine time unit plus
+            // half a nano second from the rounding to nano seconds
+            let deviation = (timespec2 - timespec).num_nanoseconds().unwrap().unsigned_abs() as u128;
+            prop_assert!(((2 * deviation) << (8 * fine_octets)) <= (1000000000 + (1 << (8 * fine_octets))));
+            // the second round trip is stable
+            let mut time2 = cuc_time::Time::new_from_format(format);
+            time2.init_from_timespec_with(timespec2, cuc_time::RoundingMode::Nearest).unwrap();
+            prop_assert_eq!(time2.dump_str(), time.dump_str());
+        }
+
+        #[test]
+        fn fine_time3_remainder(t_fine0: u8, t_fine1: u8, t_fine2: u8) {
+            let fine_time = cuc_time::FineTime3::new_init(t_fine0, t_fine1, t_fine2);
+            let (nsec, remainder) = fine_time.to_nsec_with_remainder();
+            let cuc_time_fine = ((t_fine0 as u64) << 16) + ((t_fine1 as u64) << 8) + (t_fine2 as u64);
+            prop_assert_eq!(((nsec as u64) << 24) + (remainder as u64), cuc_time_fine * 1000000000);
+            prop_assert_eq!(nsec, fine_time.to_nsec());
+        }
+    }
 }
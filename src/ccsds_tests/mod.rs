@@ -12,5 +12,8 @@
 //*****************************************************************************
 // Module configuration                                                       *
 //*****************************************************************************
+pub mod ascii_time_tests;
 pub mod c_packet_tests;
+pub mod ccs_time_tests;
+pub mod cds_time_tests;
 pub mod cuc_time_tests;
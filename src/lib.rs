@@ -12,8 +12,14 @@
 //*****************************************************************************
 // Library configuration                                                      *
 //*****************************************************************************
+// allows derived code to use the same paths inside and outside the crate
+extern crate self as space_rust_library;
 #[macro_use]
 extern crate lazy_static;
+#[cfg(test)]
+extern crate proptest;
+#[macro_use]
+extern crate space_rust_library_derive;
 extern crate time;
 pub mod test_helpers;
 #[macro_use]
@@ -52,16 +58,36 @@ mod tests {
         ::util_tests::exception_tests::test();
     }
 
+    #[test]
+    fn test_util_layout() {
+        ::util_tests::layout_tests::test();
+    }
+
     #[test]
     fn test_util_tco() {
         ::util_tests::tco_tests::test();
     }
 
+    #[test]
+    fn test_ccsds_ascii_time() {
+        ::ccsds_tests::ascii_time_tests::test();
+    }
+
     #[test]
     fn test_ccsds_c_packet() {
         ::ccsds_tests::c_packet_tests::test();
     }
 
+    #[test]
+    fn test_ccsds_ccs_time() {
+        ::ccsds_tests::ccs_time_tests::test();
+    }
+
+    #[test]
+    fn test_ccsds_cds_time() {
+        ::ccsds_tests::cds_time_tests::test();
+    }
+
     #[test]
     fn test_ccsds_cuc_time() {
         ::ccsds_tests::cuc_time_tests::test();
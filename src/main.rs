@@ -11,17 +11,57 @@
 // for more details.                                                          *
 //*****************************************************************************
 // Executable configuration                                                   *
+//                                                                            *
+// Without options the unit tests are executed, the options                   *
+//                                                                            *
+//     --check-config                                                         *
+//     --print-config                                                         *
+//                                                                            *
+// validate the configuration file against the declared config keys or       *
+// print the effective configuration with the origin of each value.          *
 //*****************************************************************************
 extern crate space_rust_library;
 
+use space_rust_library::util::config;
+use space_rust_library::util::tco;
+use std::env;
+use std::process;
+
 fn main() {
+    if env::args().any(|argument| argument == "--check-config") {
+        process::exit(check_config());
+    }
+    if env::args().any(|argument| argument == "--print-config") {
+        tco::register_config_keys();
+        config::dump_effective_config();
+        return;
+    }
     space_rust_library::util_tests::asd_time_tests::test();
     space_rust_library::util_tests::config_tests::test();
     space_rust_library::util_tests::crc_tests::test();
     space_rust_library::util_tests::du_tests::test();
     space_rust_library::util_tests::exception_tests::test();
+    space_rust_library::util_tests::layout_tests::test();
     space_rust_library::util_tests::tco_tests::test();
+    space_rust_library::ccsds_tests::ascii_time_tests::test();
     space_rust_library::ccsds_tests::c_packet_tests::test();
+    space_rust_library::ccsds_tests::ccs_time_tests::test();
+    space_rust_library::ccsds_tests::cds_time_tests::test();
     space_rust_library::ccsds_tests::cuc_time_tests::test();
     space_rust_library::pus_tests::p_packet_tests::test();
 }
+
+// prints the validation report, returns the exit code
+fn check_config() -> i32 {
+    tco::register_config_keys();
+    match config::validate() {
+        Ok(report) => {
+            print!("{}", report);
+            if report.is_ok() { 0 } else { 1 }
+        },
+        Err(err) => {
+            println!("{}", err);
+            1
+        }
+    }
+}
@@ -15,12 +15,9 @@
 use ccsds::c_packet;
 use ccsds::c_packet::PacketIntf;
 //use ccsds::cuc_time;
-use std::ops;
 //use std::u32;
 //use util::crc;
 use util::du;
-use util::du::DUintf;
-//use util::exception;
 
 //######################
 // Packet...PUS Packet #
@@ -29,39 +26,13 @@ use util::du::DUintf;
 ///////////////////////////
 // implementation struct //
 ///////////////////////////
+#[derive(DataUnit)]
+#[data_unit(no_constructors)]
 pub struct Packet<'a> {
     buffer: du::HybridVector<'a>
 }
 
 // trait implementations
-impl<'a> ops::Index<usize> for Packet<'a> {
-    type Output = u8;
-    fn index(&self, pos: usize) -> &u8 {
-        self.at(pos)
-    }
-}
-
-impl<'a> ops::IndexMut<usize> for Packet<'a> {
-    fn index_mut(&mut self, pos: usize) -> &mut u8 {
-        self.at_mut(pos)
-    }
-}
-
-impl<'a> du::DUintf for Packet<'a> {
-    // returns a read-only reference
-    fn buffer_read_only(&self) -> &[u8] {
-        self.buffer.read_only()
-    }
-    // returns a read-write reference 
-    fn buffer_read_write(&mut self) -> &mut [u8] {
-        self.buffer.read_write()
-    }
-    // change size
-    fn resize(&mut self, new_size: usize) {
-        self.buffer.resize(new_size);
-    }
-}
-
 impl<'a> PacketIntf for Packet<'a> {
 }
 
@@ -120,39 +91,13 @@ impl<'a> Packet<'a> {
 ///////////////////////////
 // implementation struct //
 ///////////////////////////
+#[derive(DataUnit)]
+#[data_unit(no_constructors)]
 pub struct TMpacket<'a> {
     buffer: du::HybridVector<'a>
 }
 
 // trait implementations
-impl<'a> ops::Index<usize> for TMpacket<'a> {
-    type Output = u8;
-    fn index(&self, pos: usize) -> &u8 {
-        self.at(pos)
-    }
-}
-
-impl<'a> ops::IndexMut<usize> for TMpacket<'a> {
-    fn index_mut(&mut self, pos: usize) -> &mut u8 {
-        self.at_mut(pos)
-    }
-}
-
-impl<'a> du::DUintf for TMpacket<'a> {
-    // returns a read-only reference
-    fn buffer_read_only(&self) -> &[u8] {
-        self.buffer.read_only()
-    }
-    // returns a read-write reference 
-    fn buffer_read_write(&mut self) -> &mut [u8] {
-        self.buffer.read_write()
-    }
-    // change size
-    fn resize(&mut self, new_size: usize) {
-        self.buffer.resize(new_size);
-    }
-}
-
 impl<'a> PacketIntf for TMpacket<'a> {
 }
 
@@ -211,39 +156,13 @@ impl<'a> TMpacket<'a> {
 ///////////////////////////
 // implementation struct //
 ///////////////////////////
+#[derive(DataUnit)]
+#[data_unit(no_constructors)]
 pub struct TCpacket<'a> {
     buffer: du::HybridVector<'a>
 }
 
 // trait implementations
-impl<'a> ops::Index<usize> for TCpacket<'a> {
-    type Output = u8;
-    fn index(&self, pos: usize) -> &u8 {
-        self.at(pos)
-    }
-}
-
-impl<'a> ops::IndexMut<usize> for TCpacket<'a> {
-    fn index_mut(&mut self, pos: usize) -> &mut u8 {
-        self.at_mut(pos)
-    }
-}
-
-impl<'a> du::DUintf for TCpacket<'a> {
-    // returns a read-only reference
-    fn buffer_read_only(&self) -> &[u8] {
-        self.buffer.read_only()
-    }
-    // returns a read-write reference 
-    fn buffer_read_write(&mut self) -> &mut [u8] {
-        self.buffer.read_write()
-    }
-    // change size
-    fn resize(&mut self, new_size: usize) {
-        self.buffer.resize(new_size);
-    }
-}
-
 impl<'a> PacketIntf for TCpacket<'a> {
 }
 
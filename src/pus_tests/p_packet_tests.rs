@@ -19,8 +19,22 @@ use util::du;
 // some accessors from util::du and ccsds::c_packet to check compile cleaness
 def_unsigned_accessor!(UINT_ACC, 0, 0);
 def_big_unsigned_accessor!(BUINT_ACC, 0, 0);
+def_signed_accessor!(INT_ACC, 0, 0);
+def_big_signed_accessor!(BINT_ACC, 0, 0);
+def_float_accessor!(FLOAT_ACC, 0, 0);
+def_mil_float_accessor!(MIL_FLOAT_ACC, 0, 0);
+def_unsigned_le_accessor!(UINT_LE_ACC, 0, 0);
+def_big_unsigned_le_accessor!(BUINT_LE_ACC, 0, 0);
+def_signed_le_accessor!(INT_LE_ACC, 0, 0);
+def_big_signed_le_accessor!(BINT_LE_ACC, 0, 0);
+def_float_le_accessor!(FLOAT_LE_ACC, 0, 0);
 def_bit_accessor!(BIT_ACC,  0,  0);
+def_big_bit_accessor!(BBIT_ACC,  0,  0);
+def_signed_bit_accessor!(SBIT_ACC,  0,  0);
+def_big_signed_bit_accessor!(BSBIT_ACC,  0,  0);
 def_byte_accessor!(BYTE_ACC, 0, 0);
+def_string_accessor!(STR_ACC, 0, 0, du::StringPadding::Space);
+def_variable_octets_accessor!(VOCT_ACC, 0, 0);
 def_cuc_time_accessor!(CUC_TIME_ACC, 0, 0);
 
 pub fn test() {
@@ -34,6 +34,21 @@ pub fn dump_u64(val_name: &str, val: u64, expected: u64) {
     assert_eq!(val, expected);
 }
 
+pub fn dump_i32(val_name: &str, val: i32, expected: i32) {
+    println!("{} = {}", val_name, val);
+    assert_eq!(val, expected);
+}
+
+pub fn dump_i64(val_name: &str, val: i64, expected: i64) {
+    println!("{} = {}", val_name, val);
+    assert_eq!(val, expected);
+}
+
+pub fn dump_f64(val_name: &str, val: f64, expected: f64) {
+    println!("{} = {}", val_name, val);
+    assert_eq!(val, expected);
+}
+
 pub fn dump_usize(val_name: &str, val: usize, expected: usize) {
     println!("{} = {}", val_name, val);
     assert_eq!(val, expected);
@@ -0,0 +1,26 @@
+//*****************************************************************************
+// (C) 2018, Stefan Korner, Austria                                           *
+//                                                                            *
+// The Space Rust Library is free software; you can redistribute it and/or    *
+// modify it under the terms of the MIT License as published by the           *
+// Massachusetts Institute of Technology.                                     *
+//                                                                            *
+// The Space Rust Library is distributed in the hope that it will be useful,  *
+// but WITHOUT ANY WARRANTY; without even the implied warranty of             *
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the MIT License   *
+// for more details.                                                          *
+//*****************************************************************************
+// Global state helpers for unit tests                                        *
+//*****************************************************************************
+use std::sync;
+
+lazy_static! {
+    static ref GLOBAL_STATE_LOCK: sync::Mutex<()> = sync::Mutex::new(());
+}
+
+// serializes the tests that use process global state (global configuration,
+// environment variables), the tests are run in parallel threads
+pub fn lock() -> sync::MutexGuard<'static, ()> {
+    // a failed test poisons the mutex, the other tests shall still run
+    GLOBAL_STATE_LOCK.lock().unwrap_or_else(|err| err.into_inner())
+}
@@ -13,3 +13,4 @@
 // Module configuration                                                       *
 //*****************************************************************************
 pub mod assert;
+pub mod global_state;
@@ -82,12 +82,23 @@ pub fn get_time_str_with_nano(timespec: time::Timespec) -> String {
         tm.tm_nsec)
 }
 
+// returns the ASD format with the shortest fraction that keeps the given
+// number of fraction digits
+pub fn get_time_str_with_precision(timespec: time::Timespec, fraction_digits: usize) -> String {
+    match fraction_digits {
+        0 => get_time_str(timespec),
+        1..=3 => get_time_str_with_milli(timespec),
+        4..=6 => get_time_str_with_micro(timespec),
+        _ => get_time_str_with_nano(timespec),
+    }
+}
+
 // extracts a timespec from an ASD formated string
 pub fn parse_time(time_str: &str) ->
     Result<time::Timespec, exception::Exception> {
     let time_str_len = time_str.len();
     if time_str_len < 17 {
-        return Err(exception::raise(&format!("parse error: invalid string length {}", time_str_len)));
+        return Err(exception::parse_error(&format!("parse error: invalid string length {}", time_str_len), time_str));
     }
     let seconds_part = &time_str[..17];
     let seconds_fraction = &time_str[17..];
@@ -96,76 +107,75 @@ pub fn parse_time(time_str: &str) ->
         1 => 0_i32,
         2 => {
             let parse_result = seconds_fraction[1..2].parse::<i32>();
-            if parse_result.is_err() {
-                return Err(exception::raise("parse error in seconds fraction"));
+            if let Err(ref err) = parse_result {
+                return Err(exception::parse_error("parse error in seconds fraction", time_str).with_source(err.clone()));
              }
              parse_result.unwrap() * 100000000
         },
         3 => {
             let parse_result = seconds_fraction[1..3].parse::<i32>();
-            if parse_result.is_err() {
-                return Err(exception::raise("parse error in seconds fraction"));
+            if let Err(ref err) = parse_result {
+                return Err(exception::parse_error("parse error in seconds fraction", time_str).with_source(err.clone()));
              }
              parse_result.unwrap() * 10000000
         },
         4 => {
             let parse_result = seconds_fraction[1..4].parse::<i32>();
-            if parse_result.is_err() {
-                return Err(exception::raise("parse error in seconds fraction"));
+            if let Err(ref err) = parse_result {
+                return Err(exception::parse_error("parse error in seconds fraction", time_str).with_source(err.clone()));
              }
              parse_result.unwrap() * 1000000
         },
         5 => {
             let parse_result = seconds_fraction[1..5].parse::<i32>();
-            if parse_result.is_err() {
-                return Err(exception::raise("parse error in seconds fraction"));
+            if let Err(ref err) = parse_result {
+                return Err(exception::parse_error("parse error in seconds fraction", time_str).with_source(err.clone()));
              }
              parse_result.unwrap() * 100000
         },
         6 => {
             let parse_result = seconds_fraction[1..6].parse::<i32>();
-            if parse_result.is_err() {
-                return Err(exception::raise("parse error in seconds fraction"));
+            if let Err(ref err) = parse_result {
+                return Err(exception::parse_error("parse error in seconds fraction", time_str).with_source(err.clone()));
              }
              parse_result.unwrap() * 10000
         },
         7 => {
             let parse_result = seconds_fraction[1..7].parse::<i32>();
-            if parse_result.is_err() {
-                return Err(exception::raise("parse error in seconds fraction"));
+            if let Err(ref err) = parse_result {
+                return Err(exception::parse_error("parse error in seconds fraction", time_str).with_source(err.clone()));
              }
              parse_result.unwrap() * 1000
         },
         8 => {
             let parse_result = seconds_fraction[1..8].parse::<i32>();
-            if parse_result.is_err() {
-                return Err(exception::raise("parse error in seconds fraction"));
+            if let Err(ref err) = parse_result {
+                return Err(exception::parse_error("parse error in seconds fraction", time_str).with_source(err.clone()));
              }
              parse_result.unwrap() * 100
         },
         9 => {
             let parse_result = seconds_fraction[1..9].parse::<i32>();
-            if parse_result.is_err() {
-                return Err(exception::raise("parse error in seconds fraction"));
+            if let Err(ref err) = parse_result {
+                return Err(exception::parse_error("parse error in seconds fraction", time_str).with_source(err.clone()));
              }
              parse_result.unwrap() * 10
         },
         10 => {
             let parse_result = seconds_fraction[1..10].parse::<i32>();
-            if parse_result.is_err() {
-                return Err(exception::raise("parse error in seconds fraction"));
+            if let Err(ref err) = parse_result {
+                return Err(exception::parse_error("parse error in seconds fraction", time_str).with_source(err.clone()));
              }
              parse_result.unwrap()
         },
         _ => {
-             return Err(exception::raise("parse error in seconds fraction"));
+             return Err(exception::parse_error("parse error in seconds fraction", time_str));
         },
     };
-    let parse_result = time::strptime(seconds_part, "%Y.%j.%H.%M.%S");
-    if parse_result.is_err() {
-        return Err(exception::raise("parse error in seconds part"));
-    }
-    let mut tm = parse_result.unwrap();
+    let mut tm = match time::strptime(seconds_part, "%Y.%j.%H.%M.%S") {
+        Err(err) => return Err(exception::parse_error("parse error in seconds part", time_str).with_source(err)),
+        Ok(tm) => tm
+    };
     // mday and mon are 0 after the parse -->
     // set it to 1st January
     tm.tm_mday = 1;
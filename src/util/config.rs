@@ -20,16 +20,48 @@
 //                                                                            *
 // but it can be defined explizitly via command line switch                   *
 //                                                                            *
-//     config_file <file_name>                                                *
+//     config_file <file_name>   or   --config <file_name>                    *
 //                                                                            *
 // Note: A white space is needed as separator between the config_file switch  *
 //       and the <file_name>.                                                 *
 // Note: The file is searched from the local directory, when no absolute path *
 //       is used in the <file_name>.                                          *
+//                                                                            *
+// Single values can be overwritten via (repeatable) command line switch      *
+//                                                                            *
+//     --set NAME=VALUE                                                       *
+//                                                                            *
+// Precedence: defaults < file < environment < command line                   *
+// The ${VAR} interpolation is done after all layers are merged.              *
+//                                                                            *
+// The config file format supports:                                          *
+//                                                                            *
+//     NAME=VALUE        the value may contain further '=' characters         *
+//     # comment         comments are stripped until the end of the line      *
+//     [section]         following names are prefixed with "section."         *
+//     include <file>    relative to the directory of the including file      *
+//     ${VAR}            replaced by another config item or environment var   *
+//                                                                            *
+// Values can be read as lists (comma separated), booleans (true/false,       *
+// yes/no, 1/0) and durations (e.g. 500ms, 2s, 1.5h).                         *
+//                                                                            *
+// The Config struct provides the same functionality without panics, the     *
+// global accessors are wrappers around a global Config and panic on errors.  *
+// The global Config is a snapshot that can be replaced via reload(),         *
+// subscribers are notified about the new snapshot.                           *
 //*****************************************************************************
+use std::any;
 use std::collections::HashMap;
 use std::env;
+use std::error;
+use std::fmt;
 use std::fs;
+use std::path;
+use std::str;
+use std::sync;
+use std::sync::atomic;
+use std::time;
+use util::exception;
 
 ///////////////
 // constants //
@@ -39,175 +71,867 @@ use std::fs;
 static DEFAULT_CONFIG_FILE_NAME: &'static str = "config.txt";
 // config file name - command line option
 static DEFAULT_CONFIG_FILE_OPTION: &'static str = "config_file";
+// config file name - command line option
+static CONFIG_FILE_OPTION: &str = "--config";
+// config value - command line option
+static SET_VALUE_OPTION: &str = "--set";
+// protection against recursive includes
+const MAX_INCLUDE_DEPTH: usize = 16;
+// protection against recursive ${VAR} interpolation
+const MAX_INTERPOLATION_DEPTH: usize = 16;
+// duration units with their length in nanoseconds
+const DURATION_UNITS: [(&str, u64); 6] = [
+    ("ns", 1),
+    ("us", 1_000),
+    ("ms", 1_000_000),
+    ("s", 1_000_000_000),
+    ("m", 60_000_000_000),
+    ("h", 3_600_000_000_000)];
+
+// callback for configuration changes
+pub type Subscriber = dyn Fn(&Config) -> Result<(), exception::Exception> + Send + Sync;
+
+// identifier of the next subscription
+static NEXT_SUBSCRIPTION_ID: atomic::AtomicUsize = atomic::AtomicUsize::new(0);
 
 lazy_static! {
-    static ref CONFIG_NAME_VALUE_PAIRS: HashMap<String, String> =
-        read_config_name_value_pairs();
+    static ref CONFIG: sync::RwLock<Result<sync::Arc<Config>, sync::Arc<exception::Exception>>> =
+        sync::RwLock::new(read_config().map(sync::Arc::new).map_err(sync::Arc::new));
+    static ref SUBSCRIBERS: sync::Mutex<Vec<(usize, sync::Arc<Subscriber>)>> =
+        sync::Mutex::new(Vec::new());
+    static ref CONFIG_KEYS: sync::Mutex<Vec<ConfigKey>> =
+        sync::Mutex::new(Vec::new());
+    static ref UPDATE_LOCK: sync::Mutex<()> =
+        sync::Mutex::new(());
+}
+
+/////////////////
+// config keys //
+/////////////////
+
+// value type of a declared configuration entry
+#[derive(Clone, Copy, Debug)]
+pub enum ValueType {
+    String,
+    Unsigned,
+    Signed,
+    Float,
+    Bool,
+    List,
+    Duration,
+    // checked by a module specific function, e.g. for symbolic values
+    Custom(fn(&str) -> Result<(), exception::Exception>)
+}
+
+// declaration of a configuration entry that is needed by a module
+#[derive(Clone, Debug)]
+pub struct ConfigKey {
+    pub name: String,
+    pub value_type: ValueType,
+    pub default_value: Option<String>,
+    pub description: String
+}
+
+// result of a validation, sorted by name
+#[derive(Clone, Debug, Default)]
+pub struct ValidationReport {
+    // name and description of declared keys without value and default
+    pub missing: Vec<(String, String)>,
+    // name and error message of values that cannot be read
+    pub malformed: Vec<(String, String)>,
+    // names of entries that are not declared
+    pub unknown: Vec<String>
+}
+
+impl ValidationReport {
+    pub fn is_ok(&self) -> bool {
+        self.missing.is_empty() && self.malformed.is_empty() && self.unknown.is_empty()
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_ok() {
+            return writeln!(f, "configuration ok");
+        }
+        for (name, description) in &self.missing {
+            writeln!(f, "missing:   {} ({})", name, description)?;
+        }
+        for (name, message) in &self.malformed {
+            writeln!(f, "malformed: {}: {}", name, message)?;
+        }
+        for name in &self.unknown {
+            writeln!(f, "unknown:   {}", name)?;
+        }
+        Ok(())
+    }
+}
+
+//////////////////
+// value origin //
+//////////////////
+
+// source of a configuration entry, for troubleshooting
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValueOrigin {
+    // default of a registered key
+    Default,
+    // config file (or included file) with the entry
+    File(String),
+    Environment,
+    CommandLine,
+    // set via Config::set_value
+    Program
+}
+
+impl fmt::Display for ValueOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ValueOrigin::Default => write!(f, "default"),
+            ValueOrigin::File(ref file_name) => write!(f, "file {}", file_name),
+            ValueOrigin::Environment => write!(f, "environment"),
+            ValueOrigin::CommandLine => write!(f, "command line"),
+            ValueOrigin::Program => write!(f, "program")
+        }
+    }
+}
+
+//////////////////////////
+// command line options //
+//////////////////////////
+
+// config related command line options, other arguments are ignored
+#[derive(Clone, Debug)]
+pub struct CommandLineOptions {
+    pub config_file_name: String,
+    // --set NAME=VALUE in command line order
+    pub values: Vec<(String, String)>
+}
+
+impl CommandLineOptions {
+    // parses the arguments without the program name
+    pub fn new_from_args<I>(args: I) -> Result<CommandLineOptions, exception::Exception>
+        where I: IntoIterator<Item = String> {
+        let mut options = CommandLineOptions {
+            config_file_name: DEFAULT_CONFIG_FILE_NAME.to_string(),
+            values: Vec::new()
+        };
+        let mut args = args.into_iter();
+        while let Some(argument) = args.next() {
+            let (option, inline_value) = match argument.find('=') {
+                Some(equal_pos) if argument.starts_with("--") =>
+                    (&argument[..equal_pos], Some(argument[equal_pos + 1..].to_string())),
+                _ => (argument.as_str(), None)
+            };
+            let is_config_file_option =
+                option == CONFIG_FILE_OPTION || option == DEFAULT_CONFIG_FILE_OPTION;
+            if !is_config_file_option && option != SET_VALUE_OPTION {
+                continue;
+            }
+            let value = match inline_value.or_else(|| args.next()) {
+                Some(value) => value,
+                None => return Err(exception::raise(
+                    &format!("Command line option {} needs a value", option)))
+            };
+            if is_config_file_option {
+                options.config_file_name = value;
+                continue;
+            }
+            match value.find('=') {
+                Some(equal_pos) if equal_pos > 0 => options.values.push((
+                    value[..equal_pos].trim().to_string(),
+                    value[equal_pos + 1..].trim().to_string())),
+                _ => return Err(exception::parse_error(
+                    &format!("Command line option {} needs NAME=VALUE", option), &value))
+            }
+        }
+        Ok(options)
+    }
+}
+
+////////////////////////
+// configuration type //
+////////////////////////
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    name_value_pairs: HashMap<String, String>,
+    raw_values: HashMap<String, String>,
+    origins: HashMap<String, ValueOrigin>
+}
+
+impl Config {
+    //////////////////
+    // constructors //
+    //////////////////
+
+    // default constructor: empty configuration
+    pub fn new() -> Config {
+        Config {
+            name_value_pairs: HashMap::new(),
+            raw_values: HashMap::new(),
+            origins: HashMap::new()
+        }
+    }
+    // reads name/value pairs from a file
+    pub fn new_from_file(file_name: &str) ->
+        Result<Config, exception::Exception> {
+        let mut config = Config::new_from_file_raw(file_name)?;
+        config.interpolate()?;
+        Ok(config)
+    }
+    // reads name/value pairs from a string with the file format
+    pub fn new_from_str(text: &str) ->
+        Result<Config, exception::Exception> {
+        let mut config = Config::new();
+        parse_lines(text, "string", "", 0, &mut config)?;
+        config.interpolate()?;
+        Ok(config)
+    }
+    // reads name/value pairs from a file without interpolation
+    fn new_from_file_raw(file_name: &str) ->
+        Result<Config, exception::Exception> {
+        let text = match fs::read_to_string(file_name) {
+            Err(err) => return Err(exception::raise(
+                &format!("Unable to read file {}", file_name)).with_source(err)),
+            Ok(text) => text
+        };
+        let mut config = Config::new();
+        parse_lines(&text, file_name, "", 0, &mut config)?;
+        Ok(config)
+    }
+
+    ///////////////
+    // modifiers //
+    ///////////////
+
+    // overwrites values from environment variables with the same name,
+    // registered keys are taken from the environment too
+    pub fn apply_environment(&mut self) {
+        let mut names: Vec<String> = self.name_value_pairs.keys().cloned().collect();
+        for config_key in get_registered_keys() {
+            if !self.contains(&config_key.name) {
+                names.push(config_key.name);
+            }
+        }
+        for name in names {
+            if let Ok(env_value) = env::var(&name) {
+                self.insert_value(&name, &env_value, ValueOrigin::Environment);
+            }
+        }
+    }
+    // replaces ${VAR} in all values, this is done when all entries are
+    // known, so that the order of the entries does not matter,
+    // must be called again after values are overwritten
+    pub fn interpolate(&mut self) ->
+        Result<(), exception::Exception> {
+        let mut interpolated_pairs = HashMap::new();
+        for (name, value) in &self.raw_values {
+            let interpolated_value = interpolate(name, value, &self.raw_values, 0).map_err(|err|
+                exception::wrap(&format!("Cannot interpolate config item {}", name), err))?;
+            interpolated_pairs.insert(name.to_string(), interpolated_value);
+        }
+        self.name_value_pairs = interpolated_pairs;
+        Ok(())
+    }
+    // overwrites values with the --set command line options
+    pub fn apply_command_line(&mut self, options: &CommandLineOptions) {
+        for (name, value) in &options.values {
+            self.insert_value(name, value, ValueOrigin::CommandLine);
+        }
+    }
+    // sets or replaces a configuration entry
+    pub fn set_value(&mut self, value_name: &str, value: &str) {
+        self.insert_value(value_name, value, ValueOrigin::Program);
+    }
+    // sets or replaces a configuration entry with its origin,
+    // the value is interpolated by interpolate()
+    fn insert_value(&mut self, value_name: &str, value: &str, origin: ValueOrigin) {
+        self.name_value_pairs.insert(value_name.to_string(), value.to_string());
+        self.raw_values.insert(value_name.to_string(), value.to_string());
+        self.origins.insert(value_name.to_string(), origin);
+    }
+
+    ///////////////
+    // accessors //
+    ///////////////
+
+    // returns all configuration entries
+    pub fn get_name_value_pairs(&self) -> &HashMap<String, String> {
+        &self.name_value_pairs
+    }
+    // returns the origin of a configuration entry,
+    // None if the entry does neither exist nor has a registered default
+    pub fn get_origin(&self, value_name: &str) -> Option<ValueOrigin> {
+        match self.origins.get(value_name) {
+            Some(origin) => Some(origin.clone()),
+            None => get_registered_default(value_name).map(|_| ValueOrigin::Default)
+        }
+    }
+    // returns the effective entries with origin, sorted by name,
+    // including the defaults of registered keys
+    pub fn dump_effective_str(&self) -> String {
+        let mut names: Vec<String> = self.name_value_pairs.keys().cloned().collect();
+        for config_key in get_registered_keys() {
+            if config_key.default_value.is_some() && !self.contains(&config_key.name) {
+                names.push(config_key.name);
+            }
+        }
+        names.sort();
+        let mut dump = String::new();
+        for name in names {
+            if let (Ok(value), Some(origin)) = (self.get_string(&name), self.get_origin(&name)) {
+                dump.push_str(&format!("{} = {}   [{}]\n", name, value, origin));
+            }
+        }
+        dump
+    }
+    // checks if a configuration entry exists
+    pub fn contains(&self, value_name: &str) -> bool {
+        self.name_value_pairs.contains_key(value_name)
+    }
+    // returns a configuration entry as string, the default
+    // of a registered key is used if the entry does not exist
+    pub fn get_string(&self, value_name: &str) ->
+        Result<String, exception::Exception> {
+        if let Some(value) = self.name_value_pairs.get(value_name) {
+            return Ok(value.to_string());
+        }
+        match get_registered_default(value_name) {
+            Some(default_value) => Ok(default_value),
+            None => Err(exception::raise(&format!("Config item {} not found", value_name)))
+        }
+    }
+    // returns a configuration entry in the requested type,
+    // the default value is returned if the entry does not exist
+    pub fn get_or<T>(&self, value_name: &str, default_value: T) ->
+        Result<T, exception::Exception>
+        where T: str::FromStr, T::Err: error::Error + Send + Sync + 'static {
+        if !self.contains(value_name) {
+            return Ok(default_value);
+        }
+        self.get_parsed(value_name, any::type_name::<T>())
+    }
+    // returns a configuration entry as u8 value
+    pub fn get_u8(&self, value_name: &str) -> Result<u8, exception::Exception> {
+        self.get_parsed(value_name, "unsigned int 8")
+    }
+    // returns a configuration entry as u16 value
+    pub fn get_u16(&self, value_name: &str) -> Result<u16, exception::Exception> {
+        self.get_parsed(value_name, "unsigned int 16")
+    }
+    // returns a configuration entry as u32 value
+    pub fn get_u32(&self, value_name: &str) -> Result<u32, exception::Exception> {
+        self.get_parsed(value_name, "unsigned int 32")
+    }
+    // returns a configuration entry as u64 value
+    pub fn get_u64(&self, value_name: &str) -> Result<u64, exception::Exception> {
+        self.get_parsed(value_name, "unsigned int 64")
+    }
+    // returns a configuration entry as usize value
+    pub fn get_usize(&self, value_name: &str) -> Result<usize, exception::Exception> {
+        self.get_parsed(value_name, "unsigned size")
+    }
+    // returns a configuration entry as i8 value
+    pub fn get_i8(&self, value_name: &str) -> Result<i8, exception::Exception> {
+        self.get_parsed(value_name, "signed int 8")
+    }
+    // returns a configuration entry as i16 value
+    pub fn get_i16(&self, value_name: &str) -> Result<i16, exception::Exception> {
+        self.get_parsed(value_name, "signed int 16")
+    }
+    // returns a configuration entry as i32 value
+    pub fn get_i32(&self, value_name: &str) -> Result<i32, exception::Exception> {
+        self.get_parsed(value_name, "signed int 32")
+    }
+    // returns a configuration entry as i64 value
+    pub fn get_i64(&self, value_name: &str) -> Result<i64, exception::Exception> {
+        self.get_parsed(value_name, "signed int 64")
+    }
+    // returns a configuration entry as isize value
+    pub fn get_isize(&self, value_name: &str) -> Result<isize, exception::Exception> {
+        self.get_parsed(value_name, "signed size")
+    }
+    // returns a configuration entry as f32 value
+    pub fn get_f32(&self, value_name: &str) -> Result<f32, exception::Exception> {
+        self.get_parsed(value_name, "float 32")
+    }
+    // returns a configuration entry as f64 value
+    pub fn get_f64(&self, value_name: &str) -> Result<f64, exception::Exception> {
+        self.get_parsed(value_name, "float 64")
+    }
+    // returns a configuration entry as boolean value,
+    // accepts true/false, yes/no and 1/0
+    pub fn get_bool(&self, value_name: &str) -> Result<bool, exception::Exception> {
+        parse_bool(value_name, &self.get_string(value_name)?)
+    }
+    // returns a configuration entry as comma separated list,
+    // an empty value is an empty list
+    pub fn get_list(&self, value_name: &str) -> Result<Vec<String>, exception::Exception> {
+        Ok(parse_list(&self.get_string(value_name)?))
+    }
+    // returns a configuration entry as duration,
+    // the number needs one of the units ns, us, ms, s, m or h
+    pub fn get_duration(&self, value_name: &str) -> Result<time::Duration, exception::Exception> {
+        parse_duration(value_name, &self.get_string(value_name)?)
+    }
+    // parses a configuration entry, format_name is used for error messages
+    fn get_parsed<T>(&self, value_name: &str, format_name: &str) ->
+        Result<T, exception::Exception>
+        where T: str::FromStr, T::Err: error::Error + Send + Sync + 'static {
+        parse_value(value_name, &self.get_string(value_name)?, format_name)
+    }
+
+    ////////////////
+    // validation //
+    ////////////////
+
+    // checks the configuration entries against the declared keys
+    pub fn validate(&self, config_keys: &[ConfigKey]) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        for config_key in config_keys {
+            let value = match self.name_value_pairs.get(&config_key.name) {
+                Some(value) => value,
+                None => match config_key.default_value {
+                    Some(ref default_value) => default_value,
+                    None => {
+                        report.missing.push(
+                            (config_key.name.to_string(), config_key.description.to_string()));
+                        continue;
+                    }
+                }
+            };
+            if let Err(err) = check_value(&config_key.name, value, config_key.value_type) {
+                report.malformed.push((config_key.name.to_string(), err.to_string()));
+            }
+        }
+        for name in self.name_value_pairs.keys() {
+            if !config_keys.iter().any(|config_key| config_key.name == *name) {
+                report.unknown.push(name.to_string());
+            }
+        }
+        report.missing.sort();
+        report.malformed.sort();
+        report.unknown.sort();
+        report
+    }
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config::new()
+    }
 }
 
 ///////////////
 // functions //
 ///////////////
 
-// returns the name of the config file
-fn get_config_file_name() -> String {
-    // search command line options for the proper switch
-    let mut switch_found = false;
-    for argument in env::args() {
-        if switch_found {
-            return argument
-        }
-        if argument == DEFAULT_CONFIG_FILE_OPTION.to_string() {
-            switch_found = true;
-        }
+// parses a boolean value: true/false, yes/no and 1/0
+fn parse_bool(value_name: &str, value: &str) -> Result<bool, exception::Exception> {
+    match value.to_lowercase().as_str() {
+        "true" | "yes" | "1" => Ok(true),
+        "false" | "no" | "0" => Ok(false),
+        _ => Err(exception::parse_error(
+            &format!("Config item {} has no boolean format", value_name), value))
     }
-    DEFAULT_CONFIG_FILE_NAME.to_string()
 }
 
-// reads name/value pairs from file,
-// returns a hash map (--> unsorted!) with name/value pairs,
-// supports comments with #
-fn read_name_value_pair_file(file_name: &str) -> HashMap<String, String> {
-    let text = fs::read_to_string(file_name).expect(
-        &format!("Unable to read file {}", file_name));
-    let mut name_value_pairs = HashMap::new();
+// parses a comma separated list, an empty value is an empty list
+fn parse_list(value: &str) -> Vec<String> {
+    if value.is_empty() {
+        return Vec::new();
+    }
+    value.split(',').map(|item| item.trim().to_string()).collect()
+}
+
+// parses a duration, the number needs one of the units ns, us, ms, s, m or h
+fn parse_duration(value_name: &str, value: &str) -> Result<time::Duration, exception::Exception> {
+    let format_error = || exception::parse_error(
+        &format!("Config item {} has no duration format", value_name), value);
+    let unit_pos = match value.find(|c: char| c.is_alphabetic()) {
+        Some(unit_pos) => unit_pos,
+        None => return Err(format_error())
+    };
+    let number = value[..unit_pos].trim();
+    let unit = &value[unit_pos..];
+    let unit_nanos = match DURATION_UNITS.iter().find(|duration_unit| duration_unit.0 == unit) {
+        Some(duration_unit) => duration_unit.1,
+        None => return Err(format_error())
+    };
+    // integer numbers are exact, fractional numbers are rounded
+    if let Ok(integer) = number.parse::<u64>() {
+        return match integer.checked_mul(unit_nanos) {
+            Some(nanos) => Ok(time::Duration::from_nanos(nanos)),
+            None => Err(format_error())
+        };
+    }
+    match number.parse::<f64>() {
+        Ok(fraction) if fraction >= 0.0 && fraction.is_finite() =>
+            Ok(time::Duration::from_secs_f64(fraction * unit_nanos as f64 / 1e9)),
+        Ok(_) => Err(format_error()),
+        Err(err) => Err(format_error().with_source(err))
+    }
+}
+
+// parses a value with FromStr, format_name is used for error messages
+fn parse_value<T>(value_name: &str, value: &str, format_name: &str) ->
+    Result<T, exception::Exception>
+    where T: str::FromStr, T::Err: error::Error + Send + Sync + 'static {
+    match value.parse::<T>() {
+        Err(err) => Err(exception::parse_error(
+            &format!("Config item {} has no {} format", value_name, format_name),
+            value).with_source(err)),
+        Ok(parsed_value) => Ok(parsed_value)
+    }
+}
+
+// checks if a value can be read with the value type
+fn check_value(value_name: &str, value: &str, value_type: ValueType) ->
+    Result<(), exception::Exception> {
+    match value_type {
+        ValueType::String | ValueType::List => Ok(()),
+        ValueType::Unsigned => parse_value::<u64>(value_name, value, "unsigned int").map(|_| ()),
+        ValueType::Signed => parse_value::<i64>(value_name, value, "signed int").map(|_| ()),
+        ValueType::Float => parse_value::<f64>(value_name, value, "float").map(|_| ()),
+        ValueType::Bool => parse_bool(value_name, value).map(|_| ()),
+        ValueType::Duration => parse_duration(value_name, value).map(|_| ()),
+        ValueType::Custom(check) => check(value)
+    }
+}
+
+// returns the default value of a registered key
+fn get_registered_default(value_name: &str) -> Option<String> {
+    CONFIG_KEYS.lock().unwrap().iter().
+        find(|config_key| config_key.name == value_name).
+        and_then(|config_key| config_key.default_value.clone())
+}
+
+// parses the lines of a text in the config file format,
+// supports comments with #, [section] headers and include directives,
+// entries are prefixed with the current section, included files
+// start in this section
+fn parse_lines(text: &str, origin: &str, section: &str, include_depth: usize,
+    config: &mut Config) -> Result<(), exception::Exception> {
+    let mut section = section.to_string();
     for (line_nr, mut line) in text.lines().enumerate() {
         // remove comments
-        match line.find("#") {
-            Some(comment_pos) => {
-                line = &line[..comment_pos];
-            },
-            None => {}
+        if let Some(comment_pos) = line.find('#') {
+            line = &line[..comment_pos];
         }
         // remove trailing and ending white spaces
         line = line.trim();
         // skip empty lines
-        if line.len() == 0 {
+        if line.is_empty() {
+            continue;
+        }
+        // section header
+        if line.starts_with('[') && line.ends_with(']') {
+            section = line[1..line.len() - 1].trim().to_string();
             continue;
         }
-        let mut split_line = line.split('=');
-        let name = split_line.next().expect(
-            &format!("Cannot read name from file {}, line {}", file_name, line_nr));
-        let value = split_line.next().expect(
-            &format!("Cannot read value from file {}, line {}", file_name, line_nr));
-        name_value_pairs.insert(name.trim().to_string(), value.trim().to_string());
-    }
-    name_value_pairs
-}
-
-// read name/value pairs from config file
-// and overwrite values from environment variables
-fn read_config_name_value_pairs() -> HashMap<String, String> {
-    let config_file_name = get_config_file_name();
-    let mut name_value_pairs = read_name_value_pair_file(&config_file_name);
-    for (name, mut value) in &mut name_value_pairs {
-        match env::var(name) {
-            Ok(env_value) => {
-                // in-place value change
-                value.clear();
-                value.push_str(&env_value);
+        // the value may contain '=' characters
+        let (name, value) = match line.find('=') {
+            Some(equal_pos) => (line[..equal_pos].trim(), line[equal_pos + 1..].trim()),
+            None => {
+                // include directive
+                if let Some(include_file_name) = line.strip_prefix("include") {
+                    if include_file_name.starts_with(char::is_whitespace) {
+                        include_file(include_file_name.trim(), origin, &section,
+                            include_depth, config)?;
+                        continue;
+                    }
+                }
+                return Err(exception::parse_error(
+                    &format!("Cannot read value from file {}, line {}", origin, line_nr), line));
             }
-            Err(_) => {}
+        };
+        if name.is_empty() {
+            return Err(exception::parse_error(
+                &format!("Cannot read name from file {}, line {}", origin, line_nr), line));
         }
+        let name = if section.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", section, name)
+        };
+        config.insert_value(&name, value, ValueOrigin::File(origin.to_string()));
+    }
+    Ok(())
+}
+
+// parses an included file, a relative file name
+// is relative to the directory of the including file
+fn include_file(file_name: &str, origin: &str, section: &str, include_depth: usize,
+    config: &mut Config) -> Result<(), exception::Exception> {
+    if include_depth >= MAX_INCLUDE_DEPTH {
+        return Err(exception::raise(
+            &format!("Include of {} in {} is nested too deep", file_name, origin)));
+    }
+    let file_path = match path::Path::new(origin).parent() {
+        Some(directory) => directory.join(file_name),
+        None => path::PathBuf::from(file_name)
+    };
+    let text = match fs::read_to_string(&file_path) {
+        Err(err) => return Err(exception::raise(
+            &format!("Unable to read file {}", file_path.display())).with_source(err)),
+        Ok(text) => text
+    };
+    parse_lines(&text, &file_path.to_string_lossy(), section, include_depth + 1, config)
+}
+
+// replaces ${VAR} by the value of another config entry in the section of
+// the entry, of another config entry without section or, if no such entry
+// exists, of the environment variable
+fn interpolate(name: &str, value: &str, name_value_pairs: &HashMap<String, String>, depth: usize) ->
+    Result<String, exception::Exception> {
+    if depth >= MAX_INTERPOLATION_DEPTH {
+        return Err(exception::parse_error("Interpolation is nested too deep", value));
+    }
+    let mut interpolated_value = String::new();
+    let mut rest = value;
+    while let Some(start_pos) = rest.find("${") {
+        interpolated_value.push_str(&rest[..start_pos]);
+        let end_pos = match rest[start_pos..].find('}') {
+            Some(end_pos) => start_pos + end_pos,
+            None => return Err(exception::parse_error("Unterminated ${ in value", value))
+        };
+        let variable = &rest[start_pos + 2..end_pos];
+        let section_variable = match name.rfind('.') {
+            Some(dot_pos) => format!("{}.{}", &name[..dot_pos], variable),
+            None => variable.to_string()
+        };
+        let variable_name = if name_value_pairs.contains_key(&section_variable) {
+            section_variable.as_str()
+        } else {
+            variable
+        };
+        let variable_value = match name_value_pairs.get(variable_name) {
+            Some(variable_value) =>
+                interpolate(variable_name, variable_value, name_value_pairs, depth + 1)?,
+            None => match env::var(variable) {
+                Ok(env_value) => env_value,
+                Err(err) => return Err(exception::parse_error(
+                    &format!("Variable {} not found", variable), value).with_source(err))
+            }
+        };
+        interpolated_value.push_str(&variable_value);
+        rest = &rest[end_pos + 1..];
+    }
+    interpolated_value.push_str(rest);
+    Ok(interpolated_value)
+}
+
+// read name/value pairs from config file and overwrite values
+// from environment variables and command line options,
+// the interpolation is done when all layers are merged
+fn read_config() -> Result<Config, exception::Exception> {
+    let options = CommandLineOptions::new_from_args(env::args().skip(1))?;
+    let mut config = Config::new_from_file_raw(&options.config_file_name)?;
+    config.apply_environment();
+    config.apply_command_line(&options);
+    config.interpolate()?;
+    Ok(config)
+}
+
+// unwraps the global configuration, panics when it cannot be read
+fn global_config() -> sync::Arc<Config> {
+    unwrap_value(get_config())
+}
+
+// calls all subscribers with the new configuration,
+// returns the first error after all subscribers are called
+fn notify_subscribers(config: &Config) -> Result<(), exception::Exception> {
+    // the callbacks are called without lock, they may (un)subscribe
+    let subscribers: Vec<sync::Arc<Subscriber>> = SUBSCRIBERS.lock().unwrap().iter().
+        map(|subscriber| subscriber.1.clone()).
+        collect();
+    let mut result = Ok(());
+    for subscriber in subscribers {
+        if let Err(err) = subscriber(config) {
+            if result.is_ok() {
+                result = Err(exception::wrap("Subscriber rejected the configuration", err));
+            }
+        }
+    }
+    result
+}
+
+// unwraps a result of the global configuration, panics on error
+fn unwrap_value<T>(result: Result<T, exception::Exception>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(err) => panic!("{}", err)
     }
-    name_value_pairs
 }
 
 ///////////////
 // accessors //
 ///////////////
 
+// returns a snapshot of the global configuration
+// or the error from reading it
+pub fn get_config() -> Result<sync::Arc<Config>, exception::Exception> {
+    match *CONFIG.read().unwrap() {
+        Ok(ref config) => Ok(config.clone()),
+        Err(ref err) => Err(exception::wrap_shared(&err.to_string(), err.clone()))
+    }
+}
+
+// re-reads the config file and environment variables and notifies
+// the subscribers, the previous configuration is kept on read errors
+// and when a subscriber rejects the new configuration
+pub fn reload() -> Result<(), exception::Exception> {
+    set_config(read_config()?)
+}
+
+// notifies the subscribers and replaces the global configuration when
+// all subscribers accept it, otherwise the previous configuration is kept
+// and the subscribers are notified again with the previous configuration
+pub fn set_config(config: Config) -> Result<(), exception::Exception> {
+    let _update_guard = UPDATE_LOCK.lock().unwrap();
+    if let Err(err) = notify_subscribers(&config) {
+        if let Ok(previous_config) = get_config() {
+            // the result is ignored, the previous configuration was accepted before
+            let _ = notify_subscribers(&previous_config);
+        }
+        return Err(err);
+    }
+    *CONFIG.write().unwrap() = Ok(sync::Arc::new(config));
+    Ok(())
+}
+
+// registers a callback for configuration changes,
+// returns the id for unsubscribe()
+pub fn subscribe<F>(subscriber: F) -> usize
+    where F: Fn(&Config) -> Result<(), exception::Exception> + Send + Sync + 'static {
+    let subscription_id = NEXT_SUBSCRIPTION_ID.fetch_add(1, atomic::Ordering::SeqCst);
+    SUBSCRIBERS.lock().unwrap().push((subscription_id, sync::Arc::new(subscriber)));
+    subscription_id
+}
+
+// removes a callback for configuration changes
+pub fn unsubscribe(subscription_id: usize) {
+    SUBSCRIBERS.lock().unwrap().retain(|subscriber| subscriber.0 != subscription_id);
+}
+
+// declares a configuration entry that is needed by a module,
+// an existing declaration with the same name is replaced
+pub fn register_key(value_name: &str, value_type: ValueType,
+    default_value: Option<&str>, description: &str) {
+    let config_key = ConfigKey {
+        name: value_name.to_string(),
+        value_type,
+        default_value: default_value.map(|value| value.to_string()),
+        description: description.to_string()
+    };
+    let mut config_keys = CONFIG_KEYS.lock().unwrap();
+    config_keys.retain(|registered_key| registered_key.name != value_name);
+    config_keys.push(config_key);
+}
+
+// returns the declared configuration entries
+pub fn get_registered_keys() -> Vec<ConfigKey> {
+    CONFIG_KEYS.lock().unwrap().clone()
+}
+
+// checks the global configuration against the declared keys
+pub fn validate() -> Result<ValidationReport, exception::Exception> {
+    Ok(get_config()?.validate(&get_registered_keys()))
+}
+
 // forces immediate read of configuration entries
 // panics when there is no configuration file
 pub fn force_config_items_read() {
-    CONFIG_NAME_VALUE_PAIRS.len();
+    global_config();
+}
+
+// prints the effective configuration with the origin of each value
+pub fn dump_effective_config() {
+    print!("{}", global_config().dump_effective_str());
 }
 
-// returns all configuration entries
-pub fn get_config_name_value_pairs() -> &'static HashMap<String, String> {
-    &CONFIG_NAME_VALUE_PAIRS
+// returns a copy of all configuration entries of the current snapshot
+pub fn get_config_name_value_pairs() -> HashMap<String, String> {
+    global_config().get_name_value_pairs().clone()
 }
 
 // returns a configuration entry as string
 pub fn get_config_value_as_string(value_name: &str) -> String {
-    match CONFIG_NAME_VALUE_PAIRS.get(value_name) {
-        Some(value) => {
-            value.to_string()
-        },
-        None => {
-            panic!(format!("Config item {} not found", value_name));
-        }
-    }
+    unwrap_value(global_config().get_string(value_name))
 }
 
 // returns a configuration entry as u8 value
 pub fn get_config_value_as_u8(value_name: &str) -> u8 {
-    get_config_value_as_string(value_name).parse::<u8>().expect(
-        &format!("Config item {} has no unsigned int 8 format", value_name))
+    unwrap_value(global_config().get_u8(value_name))
 }
 
 // returns a configuration entry as u16 value
 pub fn get_config_value_as_u16(value_name: &str) -> u16 {
-    get_config_value_as_string(value_name).parse::<u16>().expect(
-        &format!("Config item {} has no unsigned int 16 format", value_name))
+    unwrap_value(global_config().get_u16(value_name))
 }
 
 // returns a configuration entry as u32 value
 pub fn get_config_value_as_u32(value_name: &str) -> u32 {
-    get_config_value_as_string(value_name).parse::<u32>().expect(
-        &format!("Config item {} has no unsigned int 32 format", value_name))
+    unwrap_value(global_config().get_u32(value_name))
 }
 
 // returns a configuration entry as u64 value
 pub fn get_config_value_as_u64(value_name: &str) -> u64 {
-    get_config_value_as_string(value_name).parse::<u64>().expect(
-        &format!("Config item {} has no unsigned int 64 format", value_name))
+    unwrap_value(global_config().get_u64(value_name))
 }
 
 // returns a configuration entry as usize value
 pub fn get_config_value_as_usize(value_name: &str) -> usize {
-    get_config_value_as_string(value_name).parse::<usize>().expect(
-        &format!("Config item {} has no unsigned size format", value_name))
+    unwrap_value(global_config().get_usize(value_name))
 }
 
 // returns a configuration entry as i8 value
 pub fn get_config_value_as_i8(value_name: &str) -> i8 {
-    get_config_value_as_string(value_name).parse::<i8>().expect(
-        &format!("Config item {} has no signed int 8 format", value_name))
+    unwrap_value(global_config().get_i8(value_name))
 }
 
 // returns a configuration entry as i16 value
 pub fn get_config_value_as_i16(value_name: &str) -> i16 {
-    get_config_value_as_string(value_name).parse::<i16>().expect(
-        &format!("Config item {} has no signed int 16 format", value_name))
+    unwrap_value(global_config().get_i16(value_name))
 }
 
 // returns a configuration entry as i32 value
 pub fn get_config_value_as_i32(value_name: &str) -> i32 {
-    get_config_value_as_string(value_name).parse::<i32>().expect(
-        &format!("Config item {} has no signed int 32 format", value_name))
+    unwrap_value(global_config().get_i32(value_name))
 }
 
 // returns a configuration entry as i64 value
 pub fn get_config_value_as_i64(value_name: &str) -> i64 {
-    get_config_value_as_string(value_name).parse::<i64>().expect(
-        &format!("Config item {} has no signed int 64 format", value_name))
+    unwrap_value(global_config().get_i64(value_name))
 }
 
 // returns a configuration entry as isize value
 pub fn get_config_value_as_isize(value_name: &str) -> isize {
-    get_config_value_as_string(value_name).parse::<isize>().expect(
-        &format!("Config item {} has no signed size format", value_name))
+    unwrap_value(global_config().get_isize(value_name))
 }
 
 // returns a configuration entry as f32 value
 pub fn get_config_value_as_f32(value_name: &str) -> f32 {
-    get_config_value_as_string(value_name).parse::<f32>().expect(
-        &format!("Config item {} has no float 32 format", value_name))
+    unwrap_value(global_config().get_f32(value_name))
 }
 
 // returns a configuration entry as f64 value
 pub fn get_config_value_as_f64(value_name: &str) -> f64 {
-    get_config_value_as_string(value_name).parse::<f64>().expect(
-        &format!("Config item {} has no float 64 format", value_name))
+    unwrap_value(global_config().get_f64(value_name))
+}
+
+// returns a configuration entry as boolean value
+pub fn get_config_value_as_bool(value_name: &str) -> bool {
+    unwrap_value(global_config().get_bool(value_name))
+}
+
+// returns a configuration entry as comma separated list
+pub fn get_config_value_as_list(value_name: &str) -> Vec<String> {
+    unwrap_value(global_config().get_list(value_name))
+}
+
+// returns a configuration entry as duration
+pub fn get_config_value_as_duration(value_name: &str) -> time::Duration {
+    unwrap_value(global_config().get_duration(value_name))
 }
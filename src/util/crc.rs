@@ -11,8 +11,18 @@
 // for more details.                                                          *
 //*****************************************************************************
 // Utilities - Cyclic Redundancy Check (CRC)                                  *
+//                                                                            *
+// calculate16 uses the slice-by-8 algorithm, calculate16_bitwise is the      *
+// original shift register implementation and the reference for the others.  *
+// Other CRCs are instantiated from a generic CrcDefinition (Rocksoft model)  *
+// with a table driven implementation.                                        *
+// Crc8 and Crc16 calculate the CRC incrementally for data in chunks.         *
+// The ISO checksum (ISO 8473, PUS packet error control) is no CRC but is     *
+// provided here as alternative error control.                                *
 //*****************************************************************************
 
+use std::hash;
+
 ///////////////
 // constants //
 ///////////////
@@ -54,24 +64,349 @@ const CRC8_TABLE: [u8; 256] = [
     0xba, 0x2b, 0x59, 0xc8, 0xbd, 0x2c, 0x5e, 0xcf
 ];
 
+// generator polynom for CRC-16: X^16 + X^12 + X^5 + X^0
+const CRC16_POLYNOM: u16 = 0x1021;
+// CRC-16 shift register preset with all ones
+pub const CRC16_INIT: u16 = 0xffff;
+
+// look-up tables for the slice-by-8 CRC-16 calculation,
+// table k contains the CRC of a byte followed by k zero bytes
+const CRC16_TABLES: [[u16; 256]; 8] = make_crc16_tables();
+
+// standard CRCs, the check value is the CRC of "123456789"
+pub const CRC8_SPW_RMAP: CrcDefinition = CrcDefinition {
+    name: "CRC-8/SPW-RMAP", width: 8, polynom: 0x07, init: 0x00,
+    reflect_in: true, reflect_out: true, xor_out: 0x00, check: 0x20
+};
+pub const CRC16_CCITT_FALSE: CrcDefinition = CrcDefinition {
+    name: "CRC-16/CCITT-FALSE", width: 16, polynom: 0x1021, init: 0xffff,
+    reflect_in: false, reflect_out: false, xor_out: 0x0000, check: 0x29b1
+};
+pub const CRC16_KERMIT: CrcDefinition = CrcDefinition {
+    name: "CRC-16/KERMIT", width: 16, polynom: 0x1021, init: 0x0000,
+    reflect_in: true, reflect_out: true, xor_out: 0x0000, check: 0x2189
+};
+pub const CRC16_X25: CrcDefinition = CrcDefinition {
+    name: "CRC-16/X-25", width: 16, polynom: 0x1021, init: 0xffff,
+    reflect_in: true, reflect_out: true, xor_out: 0xffff, check: 0x906e
+};
+pub const CRC32_ISO_HDLC: CrcDefinition = CrcDefinition {
+    name: "CRC-32/ISO-HDLC", width: 32, polynom: 0x04c11db7, init: 0xffffffff,
+    reflect_in: true, reflect_out: true, xor_out: 0xffffffff, check: 0xcbf43926
+};
+// CRC-32 of the CCSDS frame error control (e.g. USLP)
+pub const CRC32_MPEG2: CrcDefinition = CrcDefinition {
+    name: "CRC-32/MPEG-2", width: 32, polynom: 0x04c11db7, init: 0xffffffff,
+    reflect_in: false, reflect_out: false, xor_out: 0x00000000, check: 0x0376e6e7
+};
+pub const CRC32_C: CrcDefinition = CrcDefinition {
+    name: "CRC-32/ISCSI", width: 32, polynom: 0x1edc6f41, init: 0xffffffff,
+    reflect_in: true, reflect_out: true, xor_out: 0xffffffff, check: 0xe3069283
+};
+
+lazy_static! {
+    static ref CRC32_IEEE_CALCULATOR: Crc = Crc::new(CRC32_ISO_HDLC);
+    static ref CRC32_CCSDS_CALCULATOR: Crc = Crc::new(CRC32_MPEG2);
+}
+
+/////////////////////
+// streaming CRC-8 //
+/////////////////////
+
+// incremental CRC-8 calculation, compatible with calculate8
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Crc8 {
+    crc: u8
+}
+
+impl Crc8 {
+    pub fn new() -> Crc8 {
+        Crc8 {crc: 0}
+    }
+    // continues a calculation from a previously computed value
+    pub fn new_with_value(crc: u8) -> Crc8 {
+        Crc8 {crc}
+    }
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.crc = update8(self.crc, bytes);
+    }
+    pub fn finalize(&self) -> u8 {
+        self.crc
+    }
+    pub fn reset(&mut self) {
+        self.crc = 0;
+    }
+}
+
+impl hash::Hasher for Crc8 {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+    fn finish(&self) -> u64 {
+        self.finalize() as u64
+    }
+}
+
+//////////////////////
+// streaming CRC-16 //
+//////////////////////
+
+// incremental CRC-16 calculation, compatible with calculate16
+#[derive(Clone, Copy, Debug)]
+pub struct Crc16 {
+    crc: u16
+}
+
+impl Crc16 {
+    pub fn new() -> Crc16 {
+        Crc16 {crc: CRC16_INIT}
+    }
+    // continues a calculation from a previously computed value
+    pub fn new_with_value(crc: u16) -> Crc16 {
+        Crc16 {crc}
+    }
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.crc = update16_slice8(self.crc, bytes);
+    }
+    pub fn finalize(&self) -> u16 {
+        self.crc
+    }
+    pub fn reset(&mut self) {
+        self.crc = CRC16_INIT;
+    }
+}
+
+impl Default for Crc16 {
+    fn default() -> Crc16 {
+        Crc16::new()
+    }
+}
+
+impl hash::Hasher for Crc16 {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+    fn finish(&self) -> u64 {
+        self.finalize() as u64
+    }
+}
+
+/////////////////////
+// CRC definitions //
+/////////////////////
+
+// parameters of a CRC in the Rocksoft model, width is 1..64 bits
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CrcDefinition {
+    pub name: &'static str,
+    pub width: u8,
+    // generator polynom without the leading X^width term
+    pub polynom: u64,
+    pub init: u64,
+    pub reflect_in: bool,
+    pub reflect_out: bool,
+    pub xor_out: u64,
+    pub check: u64
+}
+
+// table driven CRC calculation for a CrcDefinition
+#[derive(Clone)]
+pub struct Crc {
+    definition: CrcDefinition,
+    table: [u64; 256]
+}
+
+impl Crc {
+    // creates the look-up table, panics on an invalid width
+    pub fn new(definition: CrcDefinition) -> Crc {
+        if definition.width == 0 || definition.width > 64 {
+            panic!("CRC width {} not in 1..64", definition.width);
+        }
+        let mut table = [0_u64; 256];
+        for (byte, entry) in table.iter_mut().enumerate() {
+            let mut crc = byte as u64;
+            if definition.reflect_in {
+                // register with reflected bit order in the low bits
+                let polynom = reflect(definition.polynom, definition.width);
+                for _ in 0..8 {
+                    crc = if (crc & 1) > 0 {(crc >> 1) ^ polynom} else {crc >> 1};
+                }
+            } else {
+                // register aligned to the top bit of the u64
+                let polynom = definition.polynom << (64 - definition.width);
+                crc <<= 56;
+                for _ in 0..8 {
+                    crc = if (crc >> 63) > 0 {(crc << 1) ^ polynom} else {crc << 1};
+                }
+            }
+            *entry = crc;
+        }
+        Crc {
+            definition,
+            table
+        }
+    }
+    pub fn definition(&self) -> &CrcDefinition {
+        &self.definition
+    }
+    // calculates the CRC from the byte array
+    pub fn checksum(&self, bytes: &[u8]) -> u64 {
+        let definition = &self.definition;
+        let shift = 64 - definition.width;
+        let mut crc = if definition.reflect_in {
+            reflect(definition.init, definition.width)
+        } else {
+            definition.init << shift
+        };
+        if definition.reflect_in {
+            for next_byte in bytes {
+                crc = (crc >> 8) ^ self.table[((crc ^ *next_byte as u64) & 0xff) as usize];
+            }
+        } else {
+            for next_byte in bytes {
+                crc = (crc << 8) ^ self.table[((crc >> 56) ^ *next_byte as u64) as usize];
+            }
+            crc >>= shift;
+        }
+        if definition.reflect_in != definition.reflect_out {
+            crc = reflect(crc, definition.width);
+        }
+        (crc ^ definition.xor_out) & width_mask(definition.width)
+    }
+}
+
 ///////////////
 // functions //
 ///////////////
 
+// reverses the order of the lower width bits
+fn reflect(value: u64, width: u8) -> u64 {
+    value.reverse_bits() >> (64 - width)
+}
+
+// mask for the lower width bits
+fn width_mask(width: u8) -> u64 {
+    u64::MAX >> (64 - width)
+}
+
+// creates the look-up tables for the slice-by-8 CRC-16 calculation
+const fn make_crc16_tables() -> [[u16; 256]; 8] {
+    let mut tables = [[0_u16; 256]; 8];
+    let mut byte = 0;
+    while byte < 256 {
+        let mut crc = (byte as u16) << 8;
+        let mut bit_no = 0;
+        while bit_no < 8 {
+            crc = if (crc & 0x8000) > 0 {(crc << 1) ^ CRC16_POLYNOM} else {crc << 1};
+            bit_no += 1;
+        }
+        tables[0][byte] = crc;
+        byte += 1;
+    }
+    let mut table_no = 1;
+    while table_no < 8 {
+        let mut byte = 0;
+        while byte < 256 {
+            let crc = tables[table_no - 1][byte];
+            tables[table_no][byte] = (crc << 8) ^ tables[0][(crc >> 8) as usize];
+            byte += 1;
+        }
+        table_no += 1;
+    }
+    tables
+}
+
 // calculates the CRC-8 from the byte array
 pub fn calculate8(bytes: &[u8], size: usize) -> u8 {
-    // calculation loop
-    let mut crc = 0_u8;
-    for i in 0..size {
-        let next_byte = bytes[i];
+    update8(0, &bytes[..size])
+}
+
+// continues the CRC-8 calculation with the byte array
+fn update8(mut crc: u8, bytes: &[u8]) -> u8 {
+    for next_byte in bytes {
         // generator polynom is: X^8 + X^2 + X^1 + X^0
-        crc = CRC8_TABLE[(crc ^ next_byte) as usize];
+        crc = CRC8_TABLE[(crc ^ *next_byte) as usize];
     }
     crc
 }
 
 // calculates the CRC-16 from the byte array
 pub fn calculate16(bytes: &[u8], size: usize) -> u16 {
+    calculate16_slice8(bytes, size)
+}
+
+// calculates the CRC-16 from the byte array with one table look-up per byte
+pub fn calculate16_table(bytes: &[u8], size: usize) -> u16 {
+    let mut crc = CRC16_INIT;
+    for next_byte in &bytes[..size] {
+        crc = (crc << 8) ^ CRC16_TABLES[0][((crc >> 8) as u8 ^ *next_byte) as usize];
+    }
+    crc
+}
+
+// calculates the CRC-16 from the byte array with 8 bytes per step
+pub fn calculate16_slice8(bytes: &[u8], size: usize) -> u16 {
+    update16_slice8(CRC16_INIT, &bytes[..size])
+}
+
+// continues the CRC-16 calculation with the byte array, 8 bytes per step
+fn update16_slice8(mut crc: u16, bytes: &[u8]) -> u16 {
+    let mut chunks = bytes.chunks_exact(8);
+    for chunk in &mut chunks {
+        let high = (crc >> 8) as u8 ^ chunk[0];
+        let low = crc as u8 ^ chunk[1];
+        crc = CRC16_TABLES[7][high as usize] ^ CRC16_TABLES[6][low as usize] ^
+              CRC16_TABLES[5][chunk[2] as usize] ^ CRC16_TABLES[4][chunk[3] as usize] ^
+              CRC16_TABLES[3][chunk[4] as usize] ^ CRC16_TABLES[2][chunk[5] as usize] ^
+              CRC16_TABLES[1][chunk[6] as usize] ^ CRC16_TABLES[0][chunk[7] as usize];
+    }
+    for next_byte in chunks.remainder() {
+        crc = (crc << 8) ^ CRC16_TABLES[0][((crc >> 8) as u8 ^ *next_byte) as usize];
+    }
+    crc
+}
+
+// calculates the CRC-32 (IEEE 802.3) from the byte array
+pub fn calculate32_ieee(bytes: &[u8], size: usize) -> u32 {
+    CRC32_IEEE_CALCULATOR.checksum(&bytes[..size]) as u32
+}
+
+// calculates the CRC-32 (CCSDS, not reflected, no final XOR) from the byte array
+pub fn calculate32_ccsds(bytes: &[u8], size: usize) -> u32 {
+    CRC32_CCSDS_CALCULATOR.checksum(&bytes[..size]) as u32
+}
+
+// calculates the ISO checksum (check bytes CK1, CK2) from the byte array,
+// the check bytes are intended to follow directly after the size bytes
+pub fn calculate_iso(bytes: &[u8], size: usize) -> u16 {
+    let (c0, c1) = iso_sums(&bytes[..size]);
+    // check bytes that make both sums zero, 0 is coded as 255
+    let ck1 = match (510 - c0 - c1) % 255 {
+        0 => 255,
+        ck1 => ck1
+    };
+    let ck2 = if c1 == 0 {255} else {c1};
+    ((ck1 << 8) | ck2) as u16
+}
+
+// checks the ISO checksum, the byte array contains the check bytes
+pub fn check_iso(bytes: &[u8], size: usize) -> bool {
+    iso_sums(&bytes[..size]) == (0, 0)
+}
+
+// running sums C0 and C1 (modulo 255) of the ISO checksum
+fn iso_sums(bytes: &[u8]) -> (u32, u32) {
+    let mut c0 = 0_u32;
+    let mut c1 = 0_u32;
+    for next_byte in bytes {
+        c0 = (c0 + *next_byte as u32) % 255;
+        c1 = (c1 + c0) % 255;
+    }
+    (c0, c1)
+}
+
+// calculates the CRC-16 from the byte array bit by bit
+pub fn calculate16_bitwise(bytes: &[u8], size: usize) -> u16 {
     // 32 bit shift register for CRC generation
     // D0  - D15  :CRC shift register
     // D16        : MSB after shift
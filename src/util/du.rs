@@ -13,9 +13,14 @@
 // Utilities - Data Unit                                                      *
 //*****************************************************************************
 use std::cmp;
+use std::marker;
+use std::mem;
 use std::ops;
 use util::exception;
 
+// #[derive(DataUnit)] generates a data unit from field declarations
+pub use space_rust_library_derive::DataUnit;
+
 ///////////////
 // constants //
 ///////////////
@@ -48,6 +53,42 @@ macro_rules! def_bit_accessor {
     };
 }
 
+#[derive(Copy, Clone, Debug)]
+pub struct BigBitAccessor {
+    pub bit_pos: usize,
+    pub bit_length: usize
+}
+#[macro_export]
+macro_rules! def_big_bit_accessor {
+    ($acc_name: ident, $bit_pos: expr, $bit_length: expr) => {
+        pub const $acc_name: du::BigBitAccessor = du::BigBitAccessor {bit_pos: $bit_pos, bit_length: $bit_length};
+    };
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct SignedBitAccessor {
+    pub bit_pos: usize,
+    pub bit_length: usize
+}
+#[macro_export]
+macro_rules! def_signed_bit_accessor {
+    ($acc_name: ident, $bit_pos: expr, $bit_length: expr) => {
+        pub const $acc_name: du::SignedBitAccessor = du::SignedBitAccessor {bit_pos: $bit_pos, bit_length: $bit_length};
+    };
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct BigSignedBitAccessor {
+    pub bit_pos: usize,
+    pub bit_length: usize
+}
+#[macro_export]
+macro_rules! def_big_signed_bit_accessor {
+    ($acc_name: ident, $bit_pos: expr, $bit_length: expr) => {
+        pub const $acc_name: du::BigSignedBitAccessor = du::BigSignedBitAccessor {bit_pos: $bit_pos, bit_length: $bit_length};
+    };
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct ByteAccessor {
     pub byte_pos: usize,
@@ -60,6 +101,48 @@ macro_rules! def_byte_accessor {
     };
 }
 
+// marker type for the attributed fields of a derived data unit,
+// the values are only stored in the buffer, the zero-sized
+// PhantomData is not considered in the dead code analysis
+pub type FieldMarker = marker::PhantomData<()>;
+
+// padding of fixed size string fields
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum StringPadding {
+    // no padding, the string must fill the whole field
+    NoPadding,
+    // padded with spaces, trailing spaces are removed when read
+    Space,
+    // padded with NUL characters, the string is terminated by the first NUL
+    Null
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct StringAccessor {
+    pub byte_pos: usize,
+    pub byte_length: usize,
+    pub padding: StringPadding
+}
+#[macro_export]
+macro_rules! def_string_accessor {
+    ($acc_name: ident, $byte_pos: expr, $byte_length: expr, $padding: expr) => {
+        pub const $acc_name: du::StringAccessor = du::StringAccessor {byte_pos: $byte_pos, byte_length: $byte_length, padding: $padding};
+    };
+}
+
+// variable octet string: length field (1 or 2 bytes) followed by the octets
+#[derive(Copy, Clone, Debug)]
+pub struct VariableOctetsAccessor {
+    pub byte_pos: usize,
+    pub length_byte_length: usize
+}
+#[macro_export]
+macro_rules! def_variable_octets_accessor {
+    ($acc_name: ident, $byte_pos: expr, $length_byte_length: expr) => {
+        pub const $acc_name: du::VariableOctetsAccessor = du::VariableOctetsAccessor {byte_pos: $byte_pos, length_byte_length: $length_byte_length};
+    };
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct UnsignedAccessor {
     pub byte_pos: usize,
@@ -84,6 +167,235 @@ macro_rules! def_big_unsigned_accessor {
     };
 }
 
+#[derive(Copy, Clone, Debug)]
+pub struct SignedAccessor {
+    pub byte_pos: usize,
+    pub byte_length: usize
+}
+#[macro_export]
+macro_rules! def_signed_accessor {
+    ($acc_name: ident, $byte_pos: expr, $byte_length: expr) => {
+        pub const $acc_name: du::SignedAccessor = du::SignedAccessor {byte_pos: $byte_pos, byte_length: $byte_length};
+    };
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct BigSignedAccessor {
+    pub byte_pos: usize,
+    pub byte_length: usize
+}
+#[macro_export]
+macro_rules! def_big_signed_accessor {
+    ($acc_name: ident, $byte_pos: expr, $byte_length: expr) => {
+        pub const $acc_name: du::BigSignedAccessor = du::BigSignedAccessor {byte_pos: $byte_pos, byte_length: $byte_length};
+    };
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct FloatAccessor {
+    pub byte_pos: usize,
+    pub byte_length: usize
+}
+#[macro_export]
+macro_rules! def_float_accessor {
+    ($acc_name: ident, $byte_pos: expr, $byte_length: expr) => {
+        pub const $acc_name: du::FloatAccessor = du::FloatAccessor {byte_pos: $byte_pos, byte_length: $byte_length};
+    };
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct MilFloatAccessor {
+    pub byte_pos: usize,
+    pub byte_length: usize
+}
+#[macro_export]
+macro_rules! def_mil_float_accessor {
+    ($acc_name: ident, $byte_pos: expr, $byte_length: expr) => {
+        pub const $acc_name: du::MilFloatAccessor = du::MilFloatAccessor {byte_pos: $byte_pos, byte_length: $byte_length};
+    };
+}
+
+// accessors for little endian fields
+#[derive(Copy, Clone, Debug)]
+pub struct UnsignedLeAccessor {
+    pub byte_pos: usize,
+    pub byte_length: usize
+}
+#[macro_export]
+macro_rules! def_unsigned_le_accessor {
+    ($acc_name: ident, $byte_pos: expr, $byte_length: expr) => {
+        pub const $acc_name: du::UnsignedLeAccessor = du::UnsignedLeAccessor {byte_pos: $byte_pos, byte_length: $byte_length};
+    };
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct BigUnsignedLeAccessor {
+    pub byte_pos: usize,
+    pub byte_length: usize
+}
+#[macro_export]
+macro_rules! def_big_unsigned_le_accessor {
+    ($acc_name: ident, $byte_pos: expr, $byte_length: expr) => {
+        pub const $acc_name: du::BigUnsignedLeAccessor = du::BigUnsignedLeAccessor {byte_pos: $byte_pos, byte_length: $byte_length};
+    };
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct SignedLeAccessor {
+    pub byte_pos: usize,
+    pub byte_length: usize
+}
+#[macro_export]
+macro_rules! def_signed_le_accessor {
+    ($acc_name: ident, $byte_pos: expr, $byte_length: expr) => {
+        pub const $acc_name: du::SignedLeAccessor = du::SignedLeAccessor {byte_pos: $byte_pos, byte_length: $byte_length};
+    };
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct BigSignedLeAccessor {
+    pub byte_pos: usize,
+    pub byte_length: usize
+}
+#[macro_export]
+macro_rules! def_big_signed_le_accessor {
+    ($acc_name: ident, $byte_pos: expr, $byte_length: expr) => {
+        pub const $acc_name: du::BigSignedLeAccessor = du::BigSignedLeAccessor {byte_pos: $byte_pos, byte_length: $byte_length};
+    };
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct FloatLeAccessor {
+    pub byte_pos: usize,
+    pub byte_length: usize
+}
+#[macro_export]
+macro_rules! def_float_le_accessor {
+    ($acc_name: ident, $byte_pos: expr, $byte_length: expr) => {
+        pub const $acc_name: du::FloatLeAccessor = du::FloatLeAccessor {byte_pos: $byte_pos, byte_length: $byte_length};
+    };
+}
+
+//////////////////////
+// helper functions //
+//////////////////////
+
+// splits a finite, non-zero value into mantissa and exponent:
+// value = mantissa * 2^exponent with 0.5 <= |mantissa| < 1,
+// returns None when the value is too small to be represented normalized
+fn split_float(value: f64) -> Option<(f64, i32)> {
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    if biased_exponent == 0 {
+        // zero or subnormal
+        return None;
+    }
+    // replace the exponent by the one of the interval [0.5, 1)
+    let mantissa_bits = (bits & !(0x7ff_u64 << 52)) | (1022_u64 << 52);
+    Some((f64::from_bits(mantissa_bits), biased_exponent - 1022))
+}
+
+// error for a float value that cannot be encoded, the values are
+// saturated to the integer range of ErrorKind::ValueOutOfRange
+fn float_out_of_range(value: f64, max: f64) -> exception::Exception {
+    exception::value_out_of_range("value out of range", value as i128, -max as i128, max as i128)
+}
+
+// encodes a value into a MIL-STD-1750A mantissa with mantissa_bits
+// (incl. sign bit) and an 8 bit exponent,
+// the mantissa is rounded to the nearest representable value
+fn encode_mil_float(value: f64, mantissa_bits: i32) ->
+    Result<(i64, i8), exception::Exception> {
+    if !value.is_finite() {
+        return Err(float_out_of_range(value, 2_f64.powi(127)));
+    }
+    let (fraction, mut exponent) = match split_float(value) {
+        // zero and values that are too small are mapped to 0.0
+        None => return Ok((0, 0)),
+        Some(split_value) => split_value,
+    };
+    let scale = (1_i64 << (mantissa_bits - 1)) as f64;
+    let mut mantissa = (fraction * scale).round() as i64;
+    // normalize the mantissa: positive values have the form 01...,
+    // negative values have the form 10...
+    if mantissa == (1_i64 << (mantissa_bits - 1)) {
+        // rounding has produced +1.0 --> use 0.5 * 2^(exponent + 1)
+        mantissa >>= 1;
+        exponent += 1;
+    } else if mantissa == -(1_i64 << (mantissa_bits - 2)) {
+        // -0.5 is not normalized --> use -1.0 * 2^(exponent - 1)
+        mantissa <<= 1;
+        exponent -= 1;
+    }
+    if exponent > 127 {
+        return Err(exception::value_out_of_range("value out of range", exponent as i128, -128, 127));
+    }
+    if exponent < -128 {
+        // underflow is mapped to 0.0
+        return Ok((0, 0));
+    }
+    Ok((mantissa, exponent as i8))
+}
+
+// decodes a MIL-STD-1750A mantissa with mantissa_bits (incl. sign bit)
+// and an 8 bit exponent into a value
+fn decode_mil_float(mantissa: i64, exponent: i8, mantissa_bits: i32) -> f64 {
+    (mantissa as f64) * 2_f64.powi((exponent as i32) - (mantissa_bits - 1))
+}
+
+// formats the bytes for an array definition, 8 bytes per line
+fn dump_array_bytes(bytes: &[u8]) -> String {
+    let mut ret_val = String::new();
+    let length = bytes.len();
+    for (i, next_byte) in bytes.iter().enumerate() {
+        if (i % 8) == 0 {
+            ret_val += "\n   ";
+        }
+        ret_val += &format!(" 0x{:02x}", next_byte);
+        if (i + 1) < length {
+            ret_val.push(',');
+        }
+    }
+    ret_val
+}
+
+// parses the output of DUintf::dump_str back into the bytes,
+// only the offset and the hex bytes of each line are evaluated
+pub fn parse_dump_str(dump: &str) -> Result<Vec<u8>, exception::Exception> {
+    let mut bytes = Vec::new();
+    if dump.trim() == "EMPTY" {
+        return Ok(bytes);
+    }
+    for line in dump.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        // line format: 4 digit offset, 16 hex bytes, ASCII representation
+        let offset_str = line.get(0..4).ok_or_else(|| exception::parse_error("invalid dump line", line))?;
+        let offset = usize::from_str_radix(offset_str, 16).
+            map_err(|_| exception::parse_error("invalid offset in dump line", line))?;
+        if offset != bytes.len() {
+            return Err(exception::parse_error("unexpected offset in dump line", line));
+        }
+        let hex_end = cmp::min(line.len(), 5 + (16 * 3));
+        let hex_str = line.get(5..hex_end).ok_or_else(|| exception::parse_error("invalid dump line", line))?;
+        let mut byte_count = 0;
+        for hex_byte in hex_str.split_whitespace() {
+            let next_byte = u8::from_str_radix(hex_byte, 16).
+                map_err(|_| exception::parse_error("invalid hex byte in dump line", line))?;
+            bytes.push(next_byte);
+            byte_count += 1;
+        }
+        if byte_count == 0 {
+            return Err(exception::parse_error("no bytes in dump line", line));
+        }
+    }
+    if bytes.is_empty() {
+        return Err(exception::parse_error("no bytes in dump", dump));
+    }
+    Ok(bytes)
+}
+
 //#################
 // DU...Data Unit #
 //#################
@@ -96,8 +408,20 @@ pub trait DUintf {
     fn buffer_read_only(&self) -> &[u8];
     // returns a read-write reference, must be implemented in struct 
     fn buffer_read_write(&mut self) -> &mut [u8];
+    // returns a read-write reference without panic,
+    // data units with read-only data override this
+    fn try_buffer_read_write(&mut self) ->
+        Result<&mut [u8], exception::Exception> {
+        Ok(self.buffer_read_write())
+    }
     // change size, must be implemented in struct
     fn resize(&mut self, new_size: usize);
+    // replaces the bytes in range by other bytes,
+    // data units without a resizable buffer do not support this
+    fn splice(&mut self, _range: ops::Range<usize>, _bytes: &[u8]) ->
+        Result<(), exception::Exception> {
+        Err(exception::raise("data unit does not support splice"))
+    }
 
     ///////////////////////
     // general accessors //
@@ -154,6 +478,125 @@ pub trait DUintf {
     fn dump(&self, prefix: &str) {
         println!("{} = {}", prefix, self.dump_str());
     }
+    // dumps the buffer to a compact single line hex string
+    fn dump_hex_str(&self) -> String {
+        let mut ret_val = String::new();
+        for next_byte in self.buffer_read_only() {
+            ret_val += &format!("{:02x}", next_byte);
+        }
+        ret_val
+    }
+    // dumps the buffer as C array definition for test fixtures,
+    // an empty buffer gives only a comment
+    fn dump_c_array_str(&self, array_name: &str) -> String {
+        let size = self.size();
+        if size == 0 {
+            // C does not support zero-length arrays
+            return format!("/* {} is empty */", array_name);
+        }
+        format!("const uint8_t {}[{}] = {{{}\n}};",
+            array_name, size, dump_array_bytes(self.buffer_read_only()))
+    }
+    // dumps the buffer as Rust array definition for test fixtures
+    fn dump_rust_array_str(&self, array_name: &str) -> String {
+        let size = self.size();
+        if size == 0 {
+            return format!("const {}: [u8; 0] = [];", array_name);
+        }
+        format!("const {}: [u8; {}] = [{}\n];",
+            array_name, size, dump_array_bytes(self.buffer_read_only()))
+    }
+
+    ////////////////////
+    // sub-unit views //
+    ////////////////////
+
+    // returns the bytes in range for wrapping in another data unit
+    fn sub_buffer(&self, range: ops::Range<usize>) ->
+        Result<&[u8], exception::Exception> {
+        // consistency checks
+        if (range.start > range.end) || (range.end > self.size()) {
+            return Err(exception::out_of_bounds("range out of buffer", range.start, range.end.saturating_sub(range.start), self.size()));
+        }
+        Ok(&self.buffer_read_only()[range])
+    }
+    fn sub_buffer_mut(&mut self, range: ops::Range<usize>) ->
+        Result<&mut [u8], exception::Exception> {
+        // consistency checks
+        if (range.start > range.end) || (range.end > self.size()) {
+            return Err(exception::out_of_bounds("range out of buffer", range.start, range.end.saturating_sub(range.start), self.size()));
+        }
+        Ok(&mut self.try_buffer_read_write()?[range])
+    }
+    // returns a data unit that views the bytes in range without copying
+    fn sub_du(&self, range: ops::Range<usize>) ->
+        Result<DU<'_>, exception::Exception> {
+        Ok(DU::new_read_only(self.sub_buffer(range)?))
+    }
+    fn sub_du_mut(&mut self, range: ops::Range<usize>) ->
+        Result<DU<'_>, exception::Exception> {
+        Ok(DU::new_read_write(self.sub_buffer_mut(range)?))
+    }
+    // maps the first byte of a view to the position in the parent buffer,
+    // returns None if the view is not located in the parent buffer
+    fn offset_in(&self, parent_buffer: &[u8]) -> Option<usize> {
+        let parent_start = parent_buffer.as_ptr() as usize;
+        let parent_end = parent_start + parent_buffer.len();
+        let start = self.buffer_read_only().as_ptr() as usize;
+        let end = start + self.size();
+        if (start < parent_start) || (end > parent_end) {
+            return None;
+        }
+        Some(start - parent_start)
+    }
+
+    ////////////////////
+    // size modifiers //
+    ////////////////////
+
+    // change size without panic, new bytes are initialized with 0
+    fn try_resize(&mut self, new_size: usize) ->
+        Result<(), exception::Exception> {
+        let size = self.size();
+        if new_size > size {
+            self.append_zeros(new_size - size)?;
+            Ok(())
+        } else {
+            self.truncate(new_size)
+        }
+    }
+    // appends size zero bytes, returns the position of the first new byte
+    fn append_zeros(&mut self, size: usize) ->
+        Result<usize, exception::Exception> {
+        let byte_pos = self.size();
+        self.splice(byte_pos..byte_pos, &vec![0; size])?;
+        Ok(byte_pos)
+    }
+    // appends bytes at the end
+    fn extend_from_slice(&mut self, bytes: &[u8]) ->
+        Result<(), exception::Exception> {
+        let byte_pos = self.size();
+        self.splice(byte_pos..byte_pos, bytes)
+    }
+    // inserts bytes in front of byte_pos
+    fn insert_bytes(&mut self, byte_pos: usize, bytes: &[u8]) ->
+        Result<(), exception::Exception> {
+        self.splice(byte_pos..byte_pos, bytes)
+    }
+    // removes the bytes in range
+    fn remove_range(&mut self, range: ops::Range<usize>) ->
+        Result<(), exception::Exception> {
+        self.splice(range, &[])
+    }
+    // shortens the buffer to new_size, has no effect if new_size is larger
+    fn truncate(&mut self, new_size: usize) ->
+        Result<(), exception::Exception> {
+        let size = self.size();
+        if new_size >= size {
+            return Ok(());
+        }
+        self.splice(new_size..size, &[])
+    }
 
     /////////////////////
     // field accessors //
@@ -178,54 +621,81 @@ pub trait DUintf {
     // bit aligned access
     fn get_bits(&self, bit_pos: usize, bit_length: usize) ->
         Result<u32, exception::Exception> {
+        // consistency checks
+        if (bit_length == 0) || (bit_length > 32) {
+            return Err(exception::raise("invalid bit_length"));
+        }
+        Ok(self.get_big_bits(bit_pos, bit_length)? as u32)
+    }
+    fn set_bits(&mut self, bit_pos: usize, bit_length: usize, value: u32) ->
+        Result<(), exception::Exception> {
+        // consistency checks
+        if (bit_length == 0) || (bit_length > 32) {
+            return Err(exception::raise("invalid bit_length"));
+        }
+        self.set_big_bits(bit_pos, bit_length, value as u64)
+    }
+    fn get_bits_acc(&self, acc: BitAccessor) ->
+        Result<u32, exception::Exception> {
+        self.get_bits(acc.bit_pos, acc.bit_length)
+    }
+    fn set_bits_acc(&mut self, acc: BitAccessor, value: u32) ->
+        Result<(), exception::Exception> {
+        self.set_bits(acc.bit_pos, acc.bit_length, value)
+    }
+
+    // big bit aligned access (up to 64 bits)
+    fn get_big_bits(&self, bit_pos: usize, bit_length: usize) ->
+        Result<u64, exception::Exception> {
         // performance optimizations:
         // - divide by 8 is replaced by >> 3 (performance)
         // - modulo 8 is replaced by & 7 (performance)
         // consistency checks
-        if (bit_length == 0) || (bit_length > 32) {
+        if (bit_length == 0) || (bit_length > 64) {
             return Err(exception::raise("invalid bit_length"));
         }
         let last_bit_pos = bit_pos + bit_length - 1;
         let last_byte_pos = last_bit_pos >> 3;
         if last_byte_pos >= self.size() {
-            return Err(exception::raise("bit_pos/bit_length out of buffer"));
+            return Err(exception::out_of_bounds("bit_pos/bit_length out of buffer", bit_pos, bit_length, self.size()));
         }
-        // accumulate the number starting with the first byte
+        // accumulate the number starting with the first byte,
+        // the value can span up to 9 bytes --> u128 is used
         let mut byte_pos = bit_pos >> 3;
         let byte = self.buffer_read_only()[byte_pos];
         // first byte: filter the highest bits that do not belong to the value
-        let first_bit_in_byte_pos = (bit_pos & 7) as u64;
+        let first_bit_in_byte_pos = (bit_pos & 7) as u128;
         let bit_filter = (1 << (8 - first_bit_in_byte_pos)) - 1;
-        let mut value = (byte as u64) & bit_filter;
+        let mut value = (byte as u128) & bit_filter;
         // next bytes...
         byte_pos += 1;
         while byte_pos <= last_byte_pos {
             let byte = self.buffer_read_only()[byte_pos];
-            value = (value << 8) + (byte as u64);
+            value = (value << 8) + (byte as u128);
             byte_pos += 1;
         }
         // last byte: remove the lowest bits that do not belong to the value
-        let last_bit_in_byte_pos = (last_bit_pos & 7) as u64;
+        let last_bit_in_byte_pos = (last_bit_pos & 7) as u128;
         value >>= 7 - last_bit_in_byte_pos;
-        Ok(value as u32)
+        Ok(value as u64)
     }
-    fn set_bits(&mut self, bit_pos: usize, bit_length: usize, value: u32) ->
+    fn set_big_bits(&mut self, bit_pos: usize, bit_length: usize, value: u64) ->
         Result<(), exception::Exception> {
         // performance optimizations:
         // - divide by 8 is replaced by >> 3 (performance)
         // - modulo 8 is replaced by & 7 (performance)
         // consistency checks
-        if (bit_length == 0) || (bit_length > 32) {
+        if (bit_length == 0) || (bit_length > 64) {
             return Err(exception::raise("invalid bit_length"));
         }
-        let max_value = (1_u64 << bit_length) - 1;
-        if (value as u64) > max_value {
-            return Err(exception::raise("value out of range"));
+        let max_value = (1_u128 << bit_length) - 1;
+        if (value as u128) > max_value {
+            return Err(exception::value_out_of_range("value out of range", value as i128, 0, max_value as i128));
         }
         let last_bit_pos = bit_pos + bit_length - 1;
         let last_byte_pos = last_bit_pos >> 3;
         if last_byte_pos >= self.size() {
-            return Err(exception::raise("bit_pos/bit_length out of buffer"));
+            return Err(exception::out_of_bounds("bit_pos/bit_length out of buffer", bit_pos, bit_length, self.size()));
         }
         // set zero-bits in the buffer where the value aligns
         let first_byte_pos = bit_pos >> 3;
@@ -245,8 +715,9 @@ pub trait DUintf {
             }
             self.buffer_read_write()[byte_pos] &= BIT_FILTER[0][last_bit_in_byte_pos];
         }
-        // fill value with trailing zero-bits to align with the position
-        let mut aligned_value = value as u64;
+        // fill value with trailing zero-bits to align with the position,
+        // the aligned value can span up to 9 bytes --> u128 is used
+        let mut aligned_value = value as u128;
         aligned_value <<= 7 - last_bit_in_byte_pos;
         // decompose the aligned_value and add it to the buffer
         // starting at byte_pos, which is at the last byte
@@ -261,13 +732,81 @@ pub trait DUintf {
         }
         Ok(())
     }
-    fn get_bits_acc(&self, acc: BitAccessor) ->
-        Result<u32, exception::Exception> {
-        self.get_bits(acc.bit_pos, acc.bit_length)
+    fn get_big_bits_acc(&self, acc: BigBitAccessor) ->
+        Result<u64, exception::Exception> {
+        self.get_big_bits(acc.bit_pos, acc.bit_length)
     }
-    fn set_bits_acc(&mut self, acc: BitAccessor, value: u32) ->
+    fn set_big_bits_acc(&mut self, acc: BigBitAccessor, value: u64) ->
         Result<(), exception::Exception> {
-        self.set_bits(acc.bit_pos, acc.bit_length, value)
+        self.set_big_bits(acc.bit_pos, acc.bit_length, value)
+    }
+
+    // signed bit aligned access (two's complement)
+    fn get_signed_bits(&self, bit_pos: usize, bit_length: usize) ->
+        Result<i32, exception::Exception> {
+        // consistency checks are done in get_bits
+        let value = self.get_bits(bit_pos, bit_length)?;
+        // sign extension: move the sign bit to the highest bit position
+        // and shift back arithmetically
+        let shift = 32 - bit_length;
+        Ok(((value << shift) as i32) >> shift)
+    }
+    fn set_signed_bits(&mut self, bit_pos: usize, bit_length: usize, value: i32) ->
+        Result<(), exception::Exception> {
+        // consistency checks
+        if (bit_length == 0) || (bit_length > 32) {
+            return Err(exception::raise("invalid bit_length"));
+        }
+        let min_value = -(1_i64 << (bit_length - 1));
+        let max_value = (1_i64 << (bit_length - 1)) - 1;
+        if ((value as i64) < min_value) || ((value as i64) > max_value) {
+            return Err(exception::value_out_of_range("value out of range", value as i128, min_value as i128, max_value as i128));
+        }
+        // cut the leading sign bits that do not fit into the field
+        let unsigned_value = ((value as i64) & ((1_i64 << bit_length) - 1)) as u32;
+        self.set_bits(bit_pos, bit_length, unsigned_value)
+    }
+    fn get_signed_bits_acc(&self, acc: SignedBitAccessor) ->
+        Result<i32, exception::Exception> {
+        self.get_signed_bits(acc.bit_pos, acc.bit_length)
+    }
+    fn set_signed_bits_acc(&mut self, acc: SignedBitAccessor, value: i32) ->
+        Result<(), exception::Exception> {
+        self.set_signed_bits(acc.bit_pos, acc.bit_length, value)
+    }
+
+    // big signed bit aligned access (two's complement, up to 64 bits)
+    fn get_big_signed_bits(&self, bit_pos: usize, bit_length: usize) ->
+        Result<i64, exception::Exception> {
+        // consistency checks are done in get_big_bits
+        let value = self.get_big_bits(bit_pos, bit_length)?;
+        // sign extension: move the sign bit to the highest bit position
+        // and shift back arithmetically
+        let shift = 64 - bit_length;
+        Ok(((value << shift) as i64) >> shift)
+    }
+    fn set_big_signed_bits(&mut self, bit_pos: usize, bit_length: usize, value: i64) ->
+        Result<(), exception::Exception> {
+        // consistency checks
+        if (bit_length == 0) || (bit_length > 64) {
+            return Err(exception::raise("invalid bit_length"));
+        }
+        let min_value = -(1_i128 << (bit_length - 1));
+        let max_value = (1_i128 << (bit_length - 1)) - 1;
+        if ((value as i128) < min_value) || ((value as i128) > max_value) {
+            return Err(exception::value_out_of_range("value out of range", value as i128, min_value, max_value));
+        }
+        // cut the leading sign bits that do not fit into the field
+        let unsigned_value = ((value as i128) & ((1_i128 << bit_length) - 1)) as u64;
+        self.set_big_bits(bit_pos, bit_length, unsigned_value)
+    }
+    fn get_big_signed_bits_acc(&self, acc: BigSignedBitAccessor) ->
+        Result<i64, exception::Exception> {
+        self.get_big_signed_bits(acc.bit_pos, acc.bit_length)
+    }
+    fn set_big_signed_bits_acc(&mut self, acc: BigSignedBitAccessor, value: i64) ->
+        Result<(), exception::Exception> {
+        self.set_big_signed_bits(acc.bit_pos, acc.bit_length, value)
     }
 
     // byte aligned access
@@ -279,7 +818,7 @@ pub trait DUintf {
         }
         let end_pos = byte_pos + byte_length;
         if end_pos > self.size() {
-            return Err(exception::raise("byte_pos/byte_length out of buffer"));
+            return Err(exception::out_of_bounds("byte_pos/byte_length out of buffer", byte_pos, byte_length, self.size()));
         }
         Ok(&self.buffer_read_only()[byte_pos..end_pos])
     }
@@ -290,7 +829,7 @@ pub trait DUintf {
             return Err(exception::raise("invalid byte_length"));
         }
         if (byte_pos + byte_length) > self.size() {
-            return Err(exception::raise("byte_pos/byte_length out of buffer"));
+            return Err(exception::out_of_bounds("byte_pos/byte_length out of buffer", byte_pos, byte_length, self.size()));
         }
         // copy the minimum of bytes defined by byte_length and bytes
         let num_bytes = cmp::min(byte_length, bytes.len());
@@ -312,6 +851,116 @@ pub trait DUintf {
         self.set_bytes(acc.byte_pos, acc.byte_length, bytes)
     }
 
+    // fixed size string access
+    fn get_string(&self, byte_pos: usize, byte_length: usize, padding: StringPadding) ->
+        Result<String, exception::Exception> {
+        // consistency checks are done in get_bytes
+        let mut bytes = self.get_bytes(byte_pos, byte_length)?;
+        // remove the padding
+        match padding {
+            StringPadding::NoPadding => {},
+            StringPadding::Space => {
+                while let Some((&b' ', leading_bytes)) = bytes.split_last() {
+                    bytes = leading_bytes;
+                }
+            },
+            StringPadding::Null => {
+                if let Some(null_pos) = bytes.iter().position(|&byte| byte == 0) {
+                    bytes = &bytes[..null_pos];
+                }
+            },
+        }
+        match String::from_utf8(bytes.to_vec()) {
+            Ok(string) => Ok(string),
+            Err(_) => Err(exception::raise("invalid characters in string")),
+        }
+    }
+    fn set_string(&mut self, byte_pos: usize, byte_length: usize, padding: StringPadding, value: &str) ->
+        Result<(), exception::Exception> {
+        // consistency checks
+        if byte_length == 0 {
+            return Err(exception::raise("invalid byte_length"));
+        }
+        let string_length = value.len();
+        if string_length > byte_length {
+            return Err(exception::value_out_of_range("string too long for byte_length",
+                string_length as i128, 0, byte_length as i128));
+        }
+        let padding_byte = match padding {
+            StringPadding::NoPadding => {
+                if string_length != byte_length {
+                    return Err(exception::value_out_of_range("string does not fill byte_length",
+                        string_length as i128, byte_length as i128, byte_length as i128));
+                }
+                0
+            },
+            StringPadding::Space => b' ',
+            StringPadding::Null => 0,
+        };
+        if (byte_pos + byte_length) > self.size() {
+            return Err(exception::out_of_bounds("byte_pos/byte_length out of buffer", byte_pos, byte_length, self.size()));
+        }
+        // copy the string and fill up the rest of the field
+        let buffer = &mut self.buffer_read_write()[byte_pos..(byte_pos + byte_length)];
+        buffer[..string_length].copy_from_slice(value.as_bytes());
+        for byte in &mut buffer[string_length..] {
+            *byte = padding_byte;
+        }
+        Ok(())
+    }
+    fn get_string_acc(&self, acc: StringAccessor) ->
+        Result<String, exception::Exception> {
+        self.get_string(acc.byte_pos, acc.byte_length, acc.padding)
+    }
+    fn set_string_acc(&mut self, acc: StringAccessor, value: &str) ->
+        Result<(), exception::Exception> {
+        self.set_string(acc.byte_pos, acc.byte_length, acc.padding, value)
+    }
+
+    // variable octet string access
+    fn get_variable_octets(&self, byte_pos: usize, length_byte_length: usize) ->
+        Result<&[u8], exception::Exception> {
+        // consistency checks
+        if (length_byte_length == 0) || (length_byte_length > 2) {
+            return Err(exception::raise("invalid byte_length"));
+        }
+        let octets_length = self.get_unsigned(byte_pos, length_byte_length)? as usize;
+        let octets_pos = byte_pos + length_byte_length;
+        let end_pos = octets_pos + octets_length;
+        if end_pos > self.size() {
+            return Err(exception::out_of_bounds("byte_pos/byte_length out of buffer", byte_pos, end_pos - byte_pos, self.size()));
+        }
+        Ok(&self.buffer_read_only()[octets_pos..end_pos])
+    }
+    fn set_variable_octets(&mut self, byte_pos: usize, length_byte_length: usize, octets: &[u8]) ->
+        Result<(), exception::Exception> {
+        // consistency checks
+        if (length_byte_length == 0) || (length_byte_length > 2) {
+            return Err(exception::raise("invalid byte_length"));
+        }
+        let octets_length = octets.len();
+        if octets_length >= (1_usize << (length_byte_length << 3)) {
+            return Err(exception::value_out_of_range("value out of range",
+                octets_length as i128, 0, (1_i128 << (length_byte_length << 3)) - 1));
+        }
+        let octets_pos = byte_pos + length_byte_length;
+        let end_pos = octets_pos + octets_length;
+        if end_pos > self.size() {
+            return Err(exception::out_of_bounds("byte_pos/byte_length out of buffer", byte_pos, end_pos - byte_pos, self.size()));
+        }
+        self.set_unsigned(byte_pos, length_byte_length, octets_length as u32)?;
+        self.buffer_read_write()[octets_pos..end_pos].copy_from_slice(octets);
+        Ok(())
+    }
+    fn get_variable_octets_acc(&self, acc: VariableOctetsAccessor) ->
+        Result<&[u8], exception::Exception> {
+        self.get_variable_octets(acc.byte_pos, acc.length_byte_length)
+    }
+    fn set_variable_octets_acc(&mut self, acc: VariableOctetsAccessor, octets: &[u8]) ->
+        Result<(), exception::Exception> {
+        self.set_variable_octets(acc.byte_pos, acc.length_byte_length, octets)
+    }
+
     // unsigned integer access
     fn get_unsigned(&self, byte_pos: usize, byte_length: usize) ->
         Result<u32, exception::Exception> {
@@ -321,7 +970,7 @@ pub trait DUintf {
         }
         let last_byte_pos = byte_pos + byte_length - 1;
         if last_byte_pos >= self.size() {
-            return Err(exception::raise("byte_pos/byte_length out of buffer"));
+            return Err(exception::out_of_bounds("byte_pos/byte_length out of buffer", byte_pos, byte_length, self.size()));
         }
         // accumulate the number starting with the first byte
         let mut value: u32 = 0;
@@ -342,10 +991,10 @@ pub trait DUintf {
         if ((byte_length == 1) && (value > 255)) ||
            ((byte_length == 2) && (value > 65535)) ||
            ((byte_length == 3) && (value > 16777215)) {
-            return Err(exception::raise("value out of range"));
+            return Err(exception::value_out_of_range("value out of range", value as i128, 0, (1_i128 << (byte_length << 3)) - 1));
         }
         if (byte_pos + byte_length) > self.size() {
-            return Err(exception::raise("byte_pos/byte_length out of buffer"));
+            return Err(exception::out_of_bounds("byte_pos/byte_length out of buffer", byte_pos, byte_length, self.size()));
         }
         // decompose the value and add it to the buffer
         // starting at next_byte_pos, which is at the last byte
@@ -381,7 +1030,7 @@ pub trait DUintf {
         }
         let last_byte_pos = byte_pos + byte_length - 1;
         if last_byte_pos >= self.size() {
-            return Err(exception::raise("byte_pos/byte_length out of buffer"));
+            return Err(exception::out_of_bounds("byte_pos/byte_length out of buffer", byte_pos, byte_length, self.size()));
         }
         // accumulate the number starting with the first byte
         let mut value: u64 = 0;
@@ -406,10 +1055,10 @@ pub trait DUintf {
            ((byte_length == 5) && (value > 1099511627775)) ||
            ((byte_length == 6) && (value > 281474976710655)) ||
            ((byte_length == 7) && (value > 72057594037927935)) {
-            return Err(exception::raise("value out of range"));
+            return Err(exception::value_out_of_range("value out of range", value as i128, 0, (1_i128 << (byte_length << 3)) - 1));
         }
         if (byte_pos + byte_length) > self.size() {
-            return Err(exception::raise("byte_pos/byte_length out of buffer"));
+            return Err(exception::out_of_bounds("byte_pos/byte_length out of buffer", byte_pos, byte_length, self.size()));
         }
         // decompose the value and add it to the buffer
         // starting at next_byte_pos, which is at the last byte
@@ -435,6 +1084,356 @@ pub trait DUintf {
         Result<(), exception::Exception> {
         self.set_big_unsigned(acc.byte_pos, acc.byte_length, value)
     }
+
+    // signed integer access (two's complement)
+    fn get_signed(&self, byte_pos: usize, byte_length: usize) ->
+        Result<i32, exception::Exception> {
+        // consistency checks are done in get_unsigned
+        let value = self.get_unsigned(byte_pos, byte_length)?;
+        // sign extension: move the sign bit to the highest bit position
+        // and shift back arithmetically
+        let shift = 32 - (byte_length << 3);
+        Ok(((value << shift) as i32) >> shift)
+    }
+    fn set_signed(&mut self, byte_pos: usize, byte_length: usize, value: i32) ->
+        Result<(), exception::Exception> {
+        // consistency checks
+        if (byte_length == 0) || (byte_length > 4) {
+            return Err(exception::raise("invalid byte_length"));
+        }
+        let bit_length = byte_length << 3;
+        let min_value = -(1_i64 << (bit_length - 1));
+        let max_value = (1_i64 << (bit_length - 1)) - 1;
+        if ((value as i64) < min_value) || ((value as i64) > max_value) {
+            return Err(exception::value_out_of_range("value out of range", value as i128, min_value as i128, max_value as i128));
+        }
+        // cut the leading sign bits that do not fit into the field
+        let unsigned_value = ((value as i64) & ((1_i64 << bit_length) - 1)) as u32;
+        self.set_unsigned(byte_pos, byte_length, unsigned_value)
+    }
+    fn get_signed_acc(&self, acc: SignedAccessor) ->
+        Result<i32, exception::Exception> {
+        self.get_signed(acc.byte_pos, acc.byte_length)
+    }
+    fn set_signed_acc(&mut self, acc: SignedAccessor, value: i32) ->
+        Result<(), exception::Exception> {
+        self.set_signed(acc.byte_pos, acc.byte_length, value)
+    }
+
+    // big signed integer access (two's complement)
+    fn get_big_signed(&self, byte_pos: usize, byte_length: usize) ->
+        Result<i64, exception::Exception> {
+        // consistency checks are done in get_big_unsigned
+        let value = self.get_big_unsigned(byte_pos, byte_length)?;
+        // sign extension: move the sign bit to the highest bit position
+        // and shift back arithmetically
+        let shift = 64 - (byte_length << 3);
+        Ok(((value << shift) as i64) >> shift)
+    }
+    fn set_big_signed(&mut self, byte_pos: usize, byte_length: usize, value: i64) ->
+        Result<(), exception::Exception> {
+        // consistency checks
+        if (byte_length == 0) || (byte_length > 8) {
+            return Err(exception::raise("invalid byte_length"));
+        }
+        let bit_length = byte_length << 3;
+        if bit_length < 64 {
+            let min_value = -(1_i64 << (bit_length - 1));
+            let max_value = (1_i64 << (bit_length - 1)) - 1;
+            if (value < min_value) || (value > max_value) {
+                return Err(exception::value_out_of_range("value out of range", value as i128, min_value as i128, max_value as i128));
+            }
+        }
+        // cut the leading sign bits that do not fit into the field
+        let unsigned_value = if bit_length < 64 {
+            (value as u64) & ((1_u64 << bit_length) - 1)
+        } else {
+            value as u64
+        };
+        self.set_big_unsigned(byte_pos, byte_length, unsigned_value)
+    }
+    fn get_big_signed_acc(&self, acc: BigSignedAccessor) ->
+        Result<i64, exception::Exception> {
+        self.get_big_signed(acc.byte_pos, acc.byte_length)
+    }
+    fn set_big_signed_acc(&mut self, acc: BigSignedAccessor, value: i64) ->
+        Result<(), exception::Exception> {
+        self.set_big_signed(acc.byte_pos, acc.byte_length, value)
+    }
+
+    // IEEE 754 floating point access:
+    // byte_length 4 = single precision, byte_length 8 = double precision
+    fn get_float(&self, byte_pos: usize, byte_length: usize) ->
+        Result<f64, exception::Exception> {
+        match byte_length {
+            4 => Ok(f32::from_bits(self.get_unsigned(byte_pos, 4)?) as f64),
+            8 => Ok(f64::from_bits(self.get_big_unsigned(byte_pos, 8)?)),
+            _ => Err(exception::raise("invalid byte_length")),
+        }
+    }
+    fn set_float(&mut self, byte_pos: usize, byte_length: usize, value: f64) ->
+        Result<(), exception::Exception> {
+        match byte_length {
+            4 => {
+                if value.is_finite() && (value.abs() > (f32::MAX as f64)) {
+                    return Err(float_out_of_range(value, f32::MAX as f64));
+                }
+                self.set_unsigned(byte_pos, 4, (value as f32).to_bits())
+            },
+            8 => self.set_big_unsigned(byte_pos, 8, value.to_bits()),
+            _ => Err(exception::raise("invalid byte_length")),
+        }
+    }
+    fn get_float_acc(&self, acc: FloatAccessor) ->
+        Result<f64, exception::Exception> {
+        self.get_float(acc.byte_pos, acc.byte_length)
+    }
+    fn set_float_acc(&mut self, acc: FloatAccessor, value: f64) ->
+        Result<(), exception::Exception> {
+        self.set_float(acc.byte_pos, acc.byte_length, value)
+    }
+
+    // MIL-STD-1750A floating point access:
+    // byte_length 4 = 24 bit mantissa + 8 bit exponent,
+    // byte_length 6 = 24 bit mantissa (MSB) + 8 bit exponent +
+    //                 16 bit mantissa (LSB)
+    fn get_mil_float(&self, byte_pos: usize, byte_length: usize) ->
+        Result<f64, exception::Exception> {
+        match byte_length {
+            4 => {
+                let mantissa = self.get_signed(byte_pos, 3)? as i64;
+                let exponent = self.get_signed(byte_pos + 3, 1)? as i8;
+                Ok(decode_mil_float(mantissa, exponent, 24))
+            },
+            6 => {
+                let mantissa_msb = self.get_signed(byte_pos, 3)? as i64;
+                let exponent = self.get_signed(byte_pos + 3, 1)? as i8;
+                let mantissa_lsb = self.get_unsigned(byte_pos + 4, 2)? as i64;
+                let mantissa = (mantissa_msb << 16) | mantissa_lsb;
+                Ok(decode_mil_float(mantissa, exponent, 40))
+            },
+            _ => Err(exception::raise("invalid byte_length")),
+        }
+    }
+    fn set_mil_float(&mut self, byte_pos: usize, byte_length: usize, value: f64) ->
+        Result<(), exception::Exception> {
+        let mantissa_bits = match byte_length {
+            4 => 24,
+            6 => 40,
+            _ => return Err(exception::raise("invalid byte_length")),
+        };
+        if (byte_pos + byte_length) > self.size() {
+            return Err(exception::out_of_bounds("byte_pos/byte_length out of buffer", byte_pos, byte_length, self.size()));
+        }
+        let (mantissa, exponent) = encode_mil_float(value, mantissa_bits)?;
+        if byte_length == 4 {
+            self.set_signed(byte_pos, 3, mantissa as i32)?;
+        } else {
+            self.set_signed(byte_pos, 3, (mantissa >> 16) as i32)?;
+            self.set_unsigned(byte_pos + 4, 2, (mantissa & 0xffff) as u32)?;
+        }
+        self.set_signed(byte_pos + 3, 1, exponent as i32)
+    }
+    fn get_mil_float_acc(&self, acc: MilFloatAccessor) ->
+        Result<f64, exception::Exception> {
+        self.get_mil_float(acc.byte_pos, acc.byte_length)
+    }
+    fn set_mil_float_acc(&mut self, acc: MilFloatAccessor, value: f64) ->
+        Result<(), exception::Exception> {
+        self.set_mil_float(acc.byte_pos, acc.byte_length, value)
+    }
+
+    // little endian unsigned integer access
+    fn get_unsigned_le(&self, byte_pos: usize, byte_length: usize) ->
+        Result<u32, exception::Exception> {
+        // consistency checks
+        if (byte_length == 0) || (byte_length > 4) {
+            return Err(exception::raise("invalid byte_length"));
+        }
+        Ok(self.get_big_unsigned_le(byte_pos, byte_length)? as u32)
+    }
+    fn set_unsigned_le(&mut self, byte_pos: usize, byte_length: usize, value: u32) ->
+        Result<(), exception::Exception> {
+        // consistency checks
+        if (byte_length == 0) || (byte_length > 4) {
+            return Err(exception::raise("invalid byte_length"));
+        }
+        self.set_big_unsigned_le(byte_pos, byte_length, value as u64)
+    }
+    fn get_unsigned_le_acc(&self, acc: UnsignedLeAccessor) ->
+        Result<u32, exception::Exception> {
+        self.get_unsigned_le(acc.byte_pos, acc.byte_length)
+    }
+    fn set_unsigned_le_acc(&mut self, acc: UnsignedLeAccessor, value: u32) ->
+        Result<(), exception::Exception> {
+        self.set_unsigned_le(acc.byte_pos, acc.byte_length, value)
+    }
+
+    // little endian big unsigned integer access
+    fn get_big_unsigned_le(&self, byte_pos: usize, byte_length: usize) ->
+        Result<u64, exception::Exception> {
+        // consistency checks
+        if (byte_length == 0) || (byte_length > 8) {
+            return Err(exception::raise("invalid byte_length"));
+        }
+        let last_byte_pos = byte_pos + byte_length - 1;
+        if last_byte_pos >= self.size() {
+            return Err(exception::out_of_bounds("byte_pos/byte_length out of buffer", byte_pos, byte_length, self.size()));
+        }
+        // accumulate the number starting with the last byte
+        let mut value: u64 = 0;
+        let mut next_byte_pos = last_byte_pos;
+        while next_byte_pos >= byte_pos {
+            let byte = self.buffer_read_only()[next_byte_pos];
+            value = (value << 8) + (byte as u64);
+            if next_byte_pos == 0 {
+                break;
+            }
+            next_byte_pos -= 1;
+        }
+        Ok(value)
+    }
+    fn set_big_unsigned_le(&mut self, byte_pos: usize, byte_length: usize, value: u64) ->
+        Result<(), exception::Exception> {
+        // consistency checks
+        if (byte_length == 0) || (byte_length > 8) {
+            return Err(exception::raise("invalid byte_length"));
+        }
+        if (byte_length < 8) && (value > ((1_u64 << (byte_length << 3)) - 1)) {
+            return Err(exception::value_out_of_range("value out of range", value as i128, 0, (1_i128 << (byte_length << 3)) - 1));
+        }
+        if (byte_pos + byte_length) > self.size() {
+            return Err(exception::out_of_bounds("byte_pos/byte_length out of buffer", byte_pos, byte_length, self.size()));
+        }
+        // decompose the value and add it to the buffer
+        // starting at next_byte_pos, which is at the first byte
+        let end_pos = byte_pos + byte_length;
+        let mut next_byte_pos = byte_pos;
+        let mut next_value = value;
+        while next_byte_pos < end_pos {
+            let byte = (next_value & 0xff) as u8;
+            self.buffer_read_write()[next_byte_pos] = byte;
+            next_value >>= 8;
+            next_byte_pos += 1;
+        }
+        Ok(())
+    }
+    fn get_big_unsigned_le_acc(&self, acc: BigUnsignedLeAccessor) ->
+        Result<u64, exception::Exception> {
+        self.get_big_unsigned_le(acc.byte_pos, acc.byte_length)
+    }
+    fn set_big_unsigned_le_acc(&mut self, acc: BigUnsignedLeAccessor, value: u64) ->
+        Result<(), exception::Exception> {
+        self.set_big_unsigned_le(acc.byte_pos, acc.byte_length, value)
+    }
+
+    // little endian signed integer access (two's complement)
+    fn get_signed_le(&self, byte_pos: usize, byte_length: usize) ->
+        Result<i32, exception::Exception> {
+        // consistency checks are done in get_unsigned_le
+        let value = self.get_unsigned_le(byte_pos, byte_length)?;
+        // sign extension: move the sign bit to the highest bit position
+        // and shift back arithmetically
+        let shift = 32 - (byte_length << 3);
+        Ok(((value << shift) as i32) >> shift)
+    }
+    fn set_signed_le(&mut self, byte_pos: usize, byte_length: usize, value: i32) ->
+        Result<(), exception::Exception> {
+        // consistency checks
+        if (byte_length == 0) || (byte_length > 4) {
+            return Err(exception::raise("invalid byte_length"));
+        }
+        let bit_length = byte_length << 3;
+        let min_value = -(1_i64 << (bit_length - 1));
+        let max_value = (1_i64 << (bit_length - 1)) - 1;
+        if ((value as i64) < min_value) || ((value as i64) > max_value) {
+            return Err(exception::value_out_of_range("value out of range", value as i128, min_value as i128, max_value as i128));
+        }
+        // cut the leading sign bits that do not fit into the field
+        let unsigned_value = ((value as i64) & ((1_i64 << bit_length) - 1)) as u32;
+        self.set_unsigned_le(byte_pos, byte_length, unsigned_value)
+    }
+    fn get_signed_le_acc(&self, acc: SignedLeAccessor) ->
+        Result<i32, exception::Exception> {
+        self.get_signed_le(acc.byte_pos, acc.byte_length)
+    }
+    fn set_signed_le_acc(&mut self, acc: SignedLeAccessor, value: i32) ->
+        Result<(), exception::Exception> {
+        self.set_signed_le(acc.byte_pos, acc.byte_length, value)
+    }
+
+    // little endian big signed integer access (two's complement)
+    fn get_big_signed_le(&self, byte_pos: usize, byte_length: usize) ->
+        Result<i64, exception::Exception> {
+        // consistency checks are done in get_big_unsigned_le
+        let value = self.get_big_unsigned_le(byte_pos, byte_length)?;
+        // sign extension: move the sign bit to the highest bit position
+        // and shift back arithmetically
+        let shift = 64 - (byte_length << 3);
+        Ok(((value << shift) as i64) >> shift)
+    }
+    fn set_big_signed_le(&mut self, byte_pos: usize, byte_length: usize, value: i64) ->
+        Result<(), exception::Exception> {
+        // consistency checks
+        if (byte_length == 0) || (byte_length > 8) {
+            return Err(exception::raise("invalid byte_length"));
+        }
+        let bit_length = byte_length << 3;
+        if bit_length < 64 {
+            let min_value = -(1_i64 << (bit_length - 1));
+            let max_value = (1_i64 << (bit_length - 1)) - 1;
+            if (value < min_value) || (value > max_value) {
+                return Err(exception::value_out_of_range("value out of range", value as i128, min_value as i128, max_value as i128));
+            }
+        }
+        // cut the leading sign bits that do not fit into the field
+        let unsigned_value = if bit_length < 64 {
+            (value as u64) & ((1_u64 << bit_length) - 1)
+        } else {
+            value as u64
+        };
+        self.set_big_unsigned_le(byte_pos, byte_length, unsigned_value)
+    }
+    fn get_big_signed_le_acc(&self, acc: BigSignedLeAccessor) ->
+        Result<i64, exception::Exception> {
+        self.get_big_signed_le(acc.byte_pos, acc.byte_length)
+    }
+    fn set_big_signed_le_acc(&mut self, acc: BigSignedLeAccessor, value: i64) ->
+        Result<(), exception::Exception> {
+        self.set_big_signed_le(acc.byte_pos, acc.byte_length, value)
+    }
+
+    // little endian IEEE 754 floating point access:
+    // byte_length 4 = single precision, byte_length 8 = double precision
+    fn get_float_le(&self, byte_pos: usize, byte_length: usize) ->
+        Result<f64, exception::Exception> {
+        match byte_length {
+            4 => Ok(f32::from_bits(self.get_unsigned_le(byte_pos, 4)?) as f64),
+            8 => Ok(f64::from_bits(self.get_big_unsigned_le(byte_pos, 8)?)),
+            _ => Err(exception::raise("invalid byte_length")),
+        }
+    }
+    fn set_float_le(&mut self, byte_pos: usize, byte_length: usize, value: f64) ->
+        Result<(), exception::Exception> {
+        match byte_length {
+            4 => {
+                if value.is_finite() && (value.abs() > (f32::MAX as f64)) {
+                    return Err(float_out_of_range(value, f32::MAX as f64));
+                }
+                self.set_unsigned_le(byte_pos, 4, (value as f32).to_bits())
+            },
+            8 => self.set_big_unsigned_le(byte_pos, 8, value.to_bits()),
+            _ => Err(exception::raise("invalid byte_length")),
+        }
+    }
+    fn get_float_le_acc(&self, acc: FloatLeAccessor) ->
+        Result<f64, exception::Exception> {
+        self.get_float_le(acc.byte_pos, acc.byte_length)
+    }
+    fn set_float_le_acc(&mut self, acc: FloatLeAccessor, value: f64) ->
+        Result<(), exception::Exception> {
+        self.set_float_le(acc.byte_pos, acc.byte_length, value)
+    }
 }
 
 /////////////////
@@ -486,46 +1485,133 @@ impl<'a> HybridVector<'a> {
             },
         }
     }
-    // returns a read-write reference
+    // returns a read-write reference,
+    // panics for read-only data
     pub fn read_write(&mut self) -> &mut [u8] {
+        match self.try_read_write() {
+            Ok(read_write_ref) => read_write_ref,
+            Err(err) => panic!("{}", err),
+        }
+    }
+    // returns a read-write reference,
+    // fails for read-only data
+    pub fn try_read_write(&mut self) ->
+        Result<&mut [u8], exception::Exception> {
         match self {
             &mut HybridVector::Owner(ref mut read_write_ref) => {
-                read_write_ref.as_mut_slice()
+                Ok(read_write_ref.as_mut_slice())
             },
             &mut HybridVector::ReadWrite(ref mut read_write_ref) => {
-                read_write_ref
+                Ok(read_write_ref)
             },
             _ => {
-                panic!("must not happen");
+                Err(exception::raise("read-only buffer cannot be modified"))
             },
         }
     }
-    // returns a read-write reference
+    // returns a read-write reference,
+    // panics for wrapped data
     pub fn mut_vec(&mut self) -> &mut Vec<u8> {
         match self {
             &mut HybridVector::Owner(ref mut read_write_ref) => {
                 read_write_ref
             },
             _ => {
-                panic!("must not happen");
+                panic!("wrapped buffer has no vector");
             },
         }
     }
-    // change size
-    pub fn resize(&mut self, new_size: usize) {
+    // replaces the bytes in range by other bytes:
+    // - owned data can grow and shrink
+    // - wrapped read-write data can only shrink, the view is shortened
+    // - wrapped read-only data can only shrink at the beginning or at the end
+    pub fn splice(&mut self, range: ops::Range<usize>, bytes: &[u8]) ->
+        Result<(), exception::Exception> {
+        // consistency checks
+        let old_size = self.read_only().len();
+        if (range.start > range.end) || (range.end > old_size) {
+            return Err(exception::out_of_bounds("range out of buffer", range.start, range.end.saturating_sub(range.start), old_size));
+        }
+        let new_size = old_size - (range.end - range.start) + bytes.len();
+        match *self {
+            HybridVector::Owner(ref mut vector) => {
+                vector.splice(range, bytes.iter().cloned());
+            },
+            HybridVector::ReadWrite(ref mut read_write_ref) => {
+                if new_size > old_size {
+                    return Err(exception::raise("wrapped buffer cannot grow"));
+                }
+                // move the tail behind the new bytes and shorten the view
+                let bytes_end = range.start + bytes.len();
+                read_write_ref[range.start..bytes_end].copy_from_slice(bytes);
+                read_write_ref.copy_within(range.end..old_size, bytes_end);
+                let whole_slice = mem::take(read_write_ref);
+                *read_write_ref = &mut whole_slice[..new_size];
+            },
+            HybridVector::ReadOnly(ref mut read_only_ref) => {
+                if !bytes.is_empty() {
+                    return Err(exception::raise("read-only buffer cannot be modified"));
+                }
+                if range.end == old_size {
+                    *read_only_ref = &read_only_ref[..range.start];
+                } else if range.start == 0 {
+                    *read_only_ref = &read_only_ref[range.end..];
+                } else {
+                    return Err(exception::raise("read-only buffer cannot be modified"));
+                }
+            },
+        }
+        Ok(())
+    }
+    // appends size zero bytes, returns the position of the first new byte
+    pub fn append_zeros(&mut self, size: usize) ->
+        Result<usize, exception::Exception> {
+        let byte_pos = self.read_only().len();
+        self.splice(byte_pos..byte_pos, &vec![0; size])?;
+        Ok(byte_pos)
+    }
+    // appends bytes at the end
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) ->
+        Result<(), exception::Exception> {
+        let byte_pos = self.read_only().len();
+        self.splice(byte_pos..byte_pos, bytes)
+    }
+    // inserts bytes in front of byte_pos
+    pub fn insert_bytes(&mut self, byte_pos: usize, bytes: &[u8]) ->
+        Result<(), exception::Exception> {
+        self.splice(byte_pos..byte_pos, bytes)
+    }
+    // removes the bytes in range
+    pub fn remove_range(&mut self, range: ops::Range<usize>) ->
+        Result<(), exception::Exception> {
+        self.splice(range, &[])
+    }
+    // shortens the buffer to new_size, has no effect if new_size is larger
+    pub fn truncate(&mut self, new_size: usize) ->
+        Result<(), exception::Exception> {
+        let size = self.read_only().len();
+        if new_size >= size {
+            return Ok(());
+        }
+        self.splice(new_size..size, &[])
+    }
+    // change size, new bytes are initialized with 0,
+    // fails when wrapped data shall grow
+    pub fn try_resize(&mut self, new_size: usize) ->
+        Result<(), exception::Exception> {
         let old_size = self.read_only().len();
         if old_size < new_size {
-            let additional = new_size - old_size;
-            self.mut_vec().reserve(additional);
-            let mut i = 0;
-            while i < new_size {
-                self.mut_vec().push(0);
-                i = i + 1;
-            }
+            self.append_zeros(new_size - old_size)?;
+            Ok(())
         } else {
-            unsafe {
-                self.mut_vec().set_len(new_size);
-            }
+            self.truncate(new_size)
+        }
+    }
+    // change size, new bytes are initialized with 0,
+    // panics when wrapped data shall grow
+    pub fn resize(&mut self, new_size: usize) {
+        if let Err(err) = self.try_resize(new_size) {
+            panic!("{}", err);
         }
     }
 }
@@ -560,10 +1646,23 @@ impl<'a> DUintf for DU<'a> {
     fn buffer_read_write(&mut self) -> &mut [u8] {
         self.buffer.read_write()
     }
+    fn try_buffer_read_write(&mut self) ->
+        Result<&mut [u8], exception::Exception> {
+        self.buffer.try_read_write()
+    }
     // change size
     fn resize(&mut self, new_size: usize) {
         self.buffer.resize(new_size);
     }
+    fn try_resize(&mut self, new_size: usize) ->
+        Result<(), exception::Exception> {
+        self.buffer.try_resize(new_size)
+    }
+    // replaces the bytes in range by other bytes
+    fn splice(&mut self, range: ops::Range<usize>, bytes: &[u8]) ->
+        Result<(), exception::Exception> {
+        self.buffer.splice(range, bytes)
+    }
 }
 
 // methods implementation
@@ -596,6 +1695,11 @@ impl<'a> DU<'a> {
             buffer: HybridVector::new_owner(value)
         }
     }
+    // parses the output of dump_str
+    pub fn new_from_dump_str(dump: &str) ->
+        Result<DU<'a>, exception::Exception> {
+        Ok(DU::new_owner(parse_dump_str(dump)?))
+    }
     // wraps data for read-only
     pub fn new_read_only(reference: &[u8]) -> DU {
         DU {
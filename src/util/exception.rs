@@ -16,16 +16,65 @@
 //*****************************************************************************
 use std::error;
 use std::fmt;
+use std::sync;
+
+/////////////////
+// error kinds //
+/////////////////
+
+// classification of the error, allows callers to react without
+// evaluating the description
+#[derive(Clone, Debug, PartialEq)]
+pub enum ErrorKind {
+    // position and length (bits or bytes) exceed the buffer size (bytes)
+    OutOfBounds {pos: usize, length: usize, size: usize},
+    // integer value or length does not fit into the field or type
+    ValueOutOfRange {value: i128, min: i128, max: i128},
+    // unsupported or unexpected CUC time p-field
+    InvalidPField {p_field: u8},
+    // input string or definition cannot be parsed
+    ParseError {input: String},
+    // checksum in the buffer does not match the calculated one
+    ChecksumMismatch {expected: u32, actual: u32},
+    // length field does not match the buffer size
+    LengthMismatch {expected: usize, actual: usize},
+    // all other errors, e.g. invalid parameters
+    Other
+}
+
+//////////////////
+// error struct //
+//////////////////
 
 #[derive(Debug)]
 pub struct Exception {
-    description: String
+    description: String,
+    kind: ErrorKind,
+    source: Option<Box<dyn error::Error + Send + Sync>>
+}
+
+impl Exception {
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+    // attaches the underlying cause, which is returned by source()
+    pub fn with_source<E>(mut self, source: E) -> Exception
+        where E: Into<Box<dyn error::Error + Send + Sync>> {
+        self.source = Some(source.into());
+        self
+    }
 }
 
 impl error::Error for Exception {
     fn description(&self) -> &str {
         &self.description
     }
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self.source {
+            Some(ref source) => Some(&**source),
+            None => None
+        }
+    }
 }
 
 impl fmt::Display for Exception {
@@ -34,8 +83,52 @@ impl fmt::Display for Exception {
     }
 }
 
-pub fn raise(description: &str) -> Exception {
+//////////////////
+// constructors //
+//////////////////
+
+fn new(description: &str, kind: ErrorKind) -> Exception {
     Exception {
-        description: String::from(description)
+        description: String::from(description),
+        kind,
+        source: None
     }
 }
+
+pub fn raise(description: &str) -> Exception {
+    new(description, ErrorKind::Other)
+}
+
+pub fn out_of_bounds(description: &str, pos: usize, length: usize, size: usize) -> Exception {
+    new(description, ErrorKind::OutOfBounds {pos, length, size})
+}
+
+pub fn value_out_of_range(description: &str, value: i128, min: i128, max: i128) -> Exception {
+    new(description, ErrorKind::ValueOutOfRange {value, min, max})
+}
+
+pub fn invalid_p_field(description: &str, p_field: u8) -> Exception {
+    new(description, ErrorKind::InvalidPField {p_field})
+}
+
+pub fn parse_error(description: &str, input: &str) -> Exception {
+    new(description, ErrorKind::ParseError {input: String::from(input)})
+}
+
+pub fn checksum_mismatch(description: &str, expected: u32, actual: u32) -> Exception {
+    new(description, ErrorKind::ChecksumMismatch {expected, actual})
+}
+
+pub fn length_mismatch(description: &str, expected: usize, actual: usize) -> Exception {
+    new(description, ErrorKind::LengthMismatch {expected, actual})
+}
+
+// adds context to an error, the kind is taken over from the cause
+pub fn wrap(description: &str, source: Exception) -> Exception {
+    new(description, source.kind.clone()).with_source(source)
+}
+
+// adds context to a shared error, e.g. an error that is stored globally
+pub fn wrap_shared(description: &str, source: sync::Arc<Exception>) -> Exception {
+    new(description, source.kind.clone()).with_source(source)
+}
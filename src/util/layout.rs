@@ -0,0 +1,322 @@
+//*****************************************************************************
+// (C) 2018, Stefan Korner, Austria                                           *
+//                                                                            *
+// The Space Rust Library is free software; you can redistribute it and/or    *
+// modify it under the terms of the MIT License as published by the           *
+// Massachusetts Institute of Technology.                                     *
+//                                                                            *
+// The Space Rust Library is distributed in the hope that it will be useful,  *
+// but WITHOUT ANY WARRANTY; without even the implied warranty of             *
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the MIT License   *
+// for more details.                                                          *
+//*****************************************************************************
+// Utilities - Field Layout                                                   *
+//                                                                            *
+// Runtime description of the fields in a data unit. The field kinds reuse    *
+// the accessors of util::du, so a layout can be built in code or loaded      *
+// from a text definition with one field per line:                            *
+// <name> <kind> <position> <length> [<padding>]                              *
+// Positions and lengths are in bits for the bit kinds, otherwise in bytes.   *
+// For variable_octets the length is the byte length of the length field.     *
+//*****************************************************************************
+use std::fmt;
+use util::du;
+use util::du::DUintf;
+use util::exception;
+
+/////////////////
+// field kinds //
+/////////////////
+
+#[derive(Copy, Clone, Debug)]
+pub enum FieldKind {
+    Bits(du::BitAccessor),
+    BigBits(du::BigBitAccessor),
+    SignedBits(du::SignedBitAccessor),
+    BigSignedBits(du::BigSignedBitAccessor),
+    Bytes(du::ByteAccessor),
+    String(du::StringAccessor),
+    VariableOctets(du::VariableOctetsAccessor),
+    Unsigned(du::UnsignedAccessor),
+    BigUnsigned(du::BigUnsignedAccessor),
+    Signed(du::SignedAccessor),
+    BigSigned(du::BigSignedAccessor),
+    Float(du::FloatAccessor),
+    MilFloat(du::MilFloatAccessor),
+    UnsignedLe(du::UnsignedLeAccessor),
+    BigUnsignedLe(du::BigUnsignedLeAccessor),
+    SignedLe(du::SignedLeAccessor),
+    BigSignedLe(du::BigSignedLeAccessor),
+    FloatLe(du::FloatLeAccessor)
+}
+
+impl FieldKind {
+    // creates a field kind from its name in a layout definition
+    pub fn from_definition(kind: &str, pos: usize, length: usize, padding: Option<&str>) ->
+        Result<FieldKind, exception::Exception> {
+        // the padding is only allowed for strings
+        if padding.is_some() && (kind != "string") {
+            return Err(exception::parse_error("padding is only allowed for string fields", kind));
+        }
+        let field_kind = match kind {
+            "bits" => FieldKind::Bits(du::BitAccessor {bit_pos: pos, bit_length: length}),
+            "big_bits" => FieldKind::BigBits(du::BigBitAccessor {bit_pos: pos, bit_length: length}),
+            "signed_bits" => FieldKind::SignedBits(du::SignedBitAccessor {bit_pos: pos, bit_length: length}),
+            "big_signed_bits" => FieldKind::BigSignedBits(du::BigSignedBitAccessor {bit_pos: pos, bit_length: length}),
+            "bytes" => FieldKind::Bytes(du::ByteAccessor {byte_pos: pos, byte_length: length}),
+            "string" => {
+                let padding = match padding {
+                    None | Some("none") => du::StringPadding::NoPadding,
+                    Some("space") => du::StringPadding::Space,
+                    Some("null") => du::StringPadding::Null,
+                    Some(padding) => return Err(exception::parse_error("invalid string padding", padding))
+                };
+                FieldKind::String(du::StringAccessor {byte_pos: pos, byte_length: length, padding})
+            },
+            "variable_octets" => FieldKind::VariableOctets(du::VariableOctetsAccessor {byte_pos: pos, length_byte_length: length}),
+            "unsigned" => FieldKind::Unsigned(du::UnsignedAccessor {byte_pos: pos, byte_length: length}),
+            "big_unsigned" => FieldKind::BigUnsigned(du::BigUnsignedAccessor {byte_pos: pos, byte_length: length}),
+            "signed" => FieldKind::Signed(du::SignedAccessor {byte_pos: pos, byte_length: length}),
+            "big_signed" => FieldKind::BigSigned(du::BigSignedAccessor {byte_pos: pos, byte_length: length}),
+            "float" => FieldKind::Float(du::FloatAccessor {byte_pos: pos, byte_length: length}),
+            "mil_float" => FieldKind::MilFloat(du::MilFloatAccessor {byte_pos: pos, byte_length: length}),
+            "unsigned_le" => FieldKind::UnsignedLe(du::UnsignedLeAccessor {byte_pos: pos, byte_length: length}),
+            "big_unsigned_le" => FieldKind::BigUnsignedLe(du::BigUnsignedLeAccessor {byte_pos: pos, byte_length: length}),
+            "signed_le" => FieldKind::SignedLe(du::SignedLeAccessor {byte_pos: pos, byte_length: length}),
+            "big_signed_le" => FieldKind::BigSignedLe(du::BigSignedLeAccessor {byte_pos: pos, byte_length: length}),
+            "float_le" => FieldKind::FloatLe(du::FloatLeAccessor {byte_pos: pos, byte_length: length}),
+            _ => return Err(exception::parse_error("invalid field kind", kind))
+        };
+        Ok(field_kind)
+    }
+}
+
+//////////////////
+// field values //
+//////////////////
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldValue {
+    Unsigned(u64),
+    Signed(i64),
+    Float(f64),
+    Bytes(Vec<u8>),
+    String(String)
+}
+
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FieldValue::Unsigned(value) => write!(f, "{}", value),
+            FieldValue::Signed(value) => write!(f, "{}", value),
+            FieldValue::Float(value) => write!(f, "{}", value),
+            FieldValue::Bytes(ref bytes) => {
+                write!(f, "[")?;
+                for (i, byte) in bytes.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{:02x}", byte)?;
+                }
+                write!(f, "]")
+            },
+            FieldValue::String(ref value) => write!(f, "\"{}\"", value)
+        }
+    }
+}
+
+// ordered map of field name to value, the order is the order in the layout
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FieldValues {
+    entries: Vec<(String, FieldValue)>
+}
+
+impl FieldValues {
+    pub fn new() -> FieldValues {
+        FieldValues {entries: Vec::new()}
+    }
+    // returns the value of the field or None if it is not in the map
+    pub fn get(&self, name: &str) -> Option<&FieldValue> {
+        self.entries.iter().find(|entry| entry.0 == name).map(|entry| &entry.1)
+    }
+    // replaces the value of the field or appends it to the map
+    pub fn set(&mut self, name: &str, value: FieldValue) {
+        match self.entries.iter_mut().find(|entry| entry.0 == name) {
+            Some(entry) => entry.1 = value,
+            None => self.entries.push((String::from(name), value))
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    pub fn iter(&self) -> ::std::slice::Iter<'_, (String, FieldValue)> {
+        self.entries.iter()
+    }
+}
+
+impl fmt::Display for FieldValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (name, value) in &self.entries {
+            writeln!(f, "{} = {}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+//////////////////
+// field layout //
+//////////////////
+
+#[derive(Clone, Debug)]
+pub struct Field {
+    pub name: String,
+    pub kind: FieldKind
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct FieldLayout {
+    pub fields: Vec<Field>
+}
+
+impl FieldLayout {
+    pub fn new() -> FieldLayout {
+        FieldLayout {fields: Vec::new()}
+    }
+    // loads the layout from a text definition, one field per line,
+    // empty lines and lines starting with # are ignored
+    pub fn from_definition(definition: &str) ->
+        Result<FieldLayout, exception::Exception> {
+        let mut layout = FieldLayout::new();
+        for (line_nr, line) in definition.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if (tokens.len() < 4) || (tokens.len() > 5) {
+                return Err(exception::parse_error(&format!(
+                    "invalid field definition in line {}", line_nr + 1), line));
+            }
+            let pos = tokens[2].parse::<usize>().map_err(|error| exception::parse_error(&format!(
+                "invalid position in line {}", line_nr + 1), line).with_source(error))?;
+            let length = tokens[3].parse::<usize>().map_err(|error| exception::parse_error(&format!(
+                "invalid length in line {}", line_nr + 1), line).with_source(error))?;
+            let kind = FieldKind::from_definition(tokens[1], pos, length, tokens.get(4).cloned()).
+                map_err(|error| exception::wrap(&format!(
+                    "{} in line {}", error, line_nr + 1), error))?;
+            layout.add_field(tokens[0], kind)?;
+        }
+        Ok(layout)
+    }
+    // appends a field, the name must be unique in the layout
+    pub fn add_field(&mut self, name: &str, kind: FieldKind) ->
+        Result<(), exception::Exception> {
+        if self.get_field(name).is_some() {
+            return Err(exception::raise(&format!("duplicate field {}", name)));
+        }
+        self.fields.push(Field {name: String::from(name), kind});
+        Ok(())
+    }
+    pub fn get_field(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+    // reads all fields of the layout from the data unit
+    pub fn decode<T: DUintf + ?Sized>(&self, du: &T) ->
+        Result<FieldValues, exception::Exception> {
+        let mut values = FieldValues::new();
+        for field in &self.fields {
+            let value = decode_field(du, field.kind)?;
+            values.set(&field.name, value);
+        }
+        Ok(values)
+    }
+    // writes the values into the data unit, fields of the layout without
+    // a value are not modified
+    pub fn encode<T: DUintf + ?Sized>(&self, du: &mut T, values: &FieldValues) ->
+        Result<(), exception::Exception> {
+        // consistency checks
+        for (name, _) in values.iter() {
+            if self.get_field(name).is_none() {
+                return Err(exception::raise(&format!("unknown field {}", name)));
+            }
+        }
+        for field in &self.fields {
+            if let Some(value) = values.get(&field.name) {
+                encode_field(du, field.kind, value).map_err(|error| exception::wrap(&format!(
+                    "{} for field {}", error, field.name), error))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+//////////////////////
+// helper functions //
+//////////////////////
+
+fn decode_field<T: DUintf + ?Sized>(du: &T, kind: FieldKind) ->
+    Result<FieldValue, exception::Exception> {
+    let value = match kind {
+        FieldKind::Bits(acc) => FieldValue::Unsigned(u64::from(du.get_bits_acc(acc)?)),
+        FieldKind::BigBits(acc) => FieldValue::Unsigned(du.get_big_bits_acc(acc)?),
+        FieldKind::SignedBits(acc) => FieldValue::Signed(i64::from(du.get_signed_bits_acc(acc)?)),
+        FieldKind::BigSignedBits(acc) => FieldValue::Signed(du.get_big_signed_bits_acc(acc)?),
+        FieldKind::Bytes(acc) => FieldValue::Bytes(du.get_bytes_acc(acc)?.to_vec()),
+        FieldKind::String(acc) => FieldValue::String(du.get_string_acc(acc)?),
+        FieldKind::VariableOctets(acc) => FieldValue::Bytes(du.get_variable_octets_acc(acc)?.to_vec()),
+        FieldKind::Unsigned(acc) => FieldValue::Unsigned(u64::from(du.get_unsigned_acc(acc)?)),
+        FieldKind::BigUnsigned(acc) => FieldValue::Unsigned(du.get_big_unsigned_acc(acc)?),
+        FieldKind::Signed(acc) => FieldValue::Signed(i64::from(du.get_signed_acc(acc)?)),
+        FieldKind::BigSigned(acc) => FieldValue::Signed(du.get_big_signed_acc(acc)?),
+        FieldKind::Float(acc) => FieldValue::Float(du.get_float_acc(acc)?),
+        FieldKind::MilFloat(acc) => FieldValue::Float(du.get_mil_float_acc(acc)?),
+        FieldKind::UnsignedLe(acc) => FieldValue::Unsigned(u64::from(du.get_unsigned_le_acc(acc)?)),
+        FieldKind::BigUnsignedLe(acc) => FieldValue::Unsigned(du.get_big_unsigned_le_acc(acc)?),
+        FieldKind::SignedLe(acc) => FieldValue::Signed(i64::from(du.get_signed_le_acc(acc)?)),
+        FieldKind::BigSignedLe(acc) => FieldValue::Signed(du.get_big_signed_le_acc(acc)?),
+        FieldKind::FloatLe(acc) => FieldValue::Float(du.get_float_le_acc(acc)?)
+    };
+    Ok(value)
+}
+
+fn encode_field<T: DUintf + ?Sized>(du: &mut T, kind: FieldKind, value: &FieldValue) ->
+    Result<(), exception::Exception> {
+    match (kind, value) {
+        (FieldKind::Bits(acc), FieldValue::Unsigned(value)) => du.set_bits_acc(acc, to_u32(*value)?),
+        (FieldKind::BigBits(acc), FieldValue::Unsigned(value)) => du.set_big_bits_acc(acc, *value),
+        (FieldKind::SignedBits(acc), FieldValue::Signed(value)) => du.set_signed_bits_acc(acc, to_i32(*value)?),
+        (FieldKind::BigSignedBits(acc), FieldValue::Signed(value)) => du.set_big_signed_bits_acc(acc, *value),
+        (FieldKind::Bytes(acc), FieldValue::Bytes(bytes)) => du.set_bytes_acc(acc, bytes),
+        (FieldKind::String(acc), FieldValue::String(value)) => du.set_string_acc(acc, value),
+        (FieldKind::VariableOctets(acc), FieldValue::Bytes(bytes)) => du.set_variable_octets_acc(acc, bytes),
+        (FieldKind::Unsigned(acc), FieldValue::Unsigned(value)) => du.set_unsigned_acc(acc, to_u32(*value)?),
+        (FieldKind::BigUnsigned(acc), FieldValue::Unsigned(value)) => du.set_big_unsigned_acc(acc, *value),
+        (FieldKind::Signed(acc), FieldValue::Signed(value)) => du.set_signed_acc(acc, to_i32(*value)?),
+        (FieldKind::BigSigned(acc), FieldValue::Signed(value)) => du.set_big_signed_acc(acc, *value),
+        (FieldKind::Float(acc), FieldValue::Float(value)) => du.set_float_acc(acc, *value),
+        (FieldKind::MilFloat(acc), FieldValue::Float(value)) => du.set_mil_float_acc(acc, *value),
+        (FieldKind::UnsignedLe(acc), FieldValue::Unsigned(value)) => du.set_unsigned_le_acc(acc, to_u32(*value)?),
+        (FieldKind::BigUnsignedLe(acc), FieldValue::Unsigned(value)) => du.set_big_unsigned_le_acc(acc, *value),
+        (FieldKind::SignedLe(acc), FieldValue::Signed(value)) => du.set_signed_le_acc(acc, to_i32(*value)?),
+        (FieldKind::BigSignedLe(acc), FieldValue::Signed(value)) => du.set_big_signed_le_acc(acc, *value),
+        (FieldKind::FloatLe(acc), FieldValue::Float(value)) => du.set_float_le_acc(acc, *value),
+        _ => Err(exception::raise("value type does not match field kind"))
+    }
+}
+
+fn to_u32(value: u64) -> Result<u32, exception::Exception> {
+    if value > u64::from(u32::MAX) {
+        return Err(exception::value_out_of_range("value out of range", value as i128, 0, u32::MAX as i128));
+    }
+    Ok(value as u32)
+}
+
+fn to_i32(value: i64) -> Result<i32, exception::Exception> {
+    if (value < i64::from(i32::MIN)) || (value > i64::from(i32::MAX)) {
+        return Err(exception::value_out_of_range("value out of range", value as i128, i32::MIN as i128, i32::MAX as i128));
+    }
+    Ok(value as i32)
+}
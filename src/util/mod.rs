@@ -18,4 +18,5 @@ pub mod crc;
 #[macro_use]
 pub mod du;
 pub mod exception;
+pub mod layout;
 pub mod tco;
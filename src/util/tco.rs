@@ -16,12 +16,15 @@
 // ERT...Earth Reception Time line                                            *
 // OBT...Onboard Time line                                                    *
 //                                                                            *
-// The module is statically configured via entries in config.txt              *
+// The module is configured via entries in config.txt, the deltas are        *
+// recomputed when the configuration is reloaded.                             *
 // Calculation are base on time::Timespec (from Crate time)                   *
 //*****************************************************************************
+use std::sync;
 use time;
 use util::asd_time;
 use util::config;
+use util::exception;
 
 ///////////////
 // constants //
@@ -38,23 +41,15 @@ pub static GPS_LEAP_SECONDS_2012: i64 = 16;
 pub static GPS_LEAP_SECONDS_2015: i64 = 17;
 pub static GPS_LEAP_SECONDS_2017: i64 = 18;
 
+// deltas of the time lines
+struct Deltas {
+    ert_delta: time::Duration,
+    obt_delta: time::Duration
+}
+
 lazy_static! {
-    static ref ERT_LEAP_SECONDS_STR: String =
-        config::get_config_value_as_string("TCO_ERT_LEAP_SECONDS_STR");
-    static ref ERT_MISSION_EPOCH_STR: String =
-        config::get_config_value_as_string("TCO_ERT_MISSION_EPOCH_STR");
-    static ref ERT_DELTA: time::Duration =
-        get_mission_epoch_with_leap_seconds_delta(
-            &ERT_MISSION_EPOCH_STR,
-            &ERT_LEAP_SECONDS_STR);
-    static ref OBT_LEAP_SECONDS_STR: String =
-        config::get_config_value_as_string("TCO_OBT_LEAP_SECONDS_STR");
-    static ref OBT_MISSION_EPOCH_STR: String =
-        config::get_config_value_as_string("TCO_OBT_MISSION_EPOCH_STR");
-    static ref OBT_DELTA: time::Duration =
-        get_mission_epoch_with_leap_seconds_delta(
-            &OBT_MISSION_EPOCH_STR,
-            &OBT_LEAP_SECONDS_STR);
+    static ref DELTAS: sync::RwLock<Deltas> =
+        sync::RwLock::new(init_deltas());
 }
 
 ///////////////
@@ -62,23 +57,27 @@ lazy_static! {
 ///////////////
 
 // converts a leap seconds string (either symbolic or numeric) to seconds
-fn get_leap_seconds(leap_seconds_str: &str) -> time::Duration {
+fn parse_leap_seconds(leap_seconds_str: &str) ->
+    Result<time::Duration, exception::Exception> {
     let leap_seconds = match leap_seconds_str {
         "GPS_LEAP_SECONDS_1980" => GPS_LEAP_SECONDS_1980,
         "GPS_LEAP_SECONDS_2009" => GPS_LEAP_SECONDS_2009,
         "GPS_LEAP_SECONDS_2012" => GPS_LEAP_SECONDS_2012,
         "GPS_LEAP_SECONDS_2015" => GPS_LEAP_SECONDS_2015,
         "GPS_LEAP_SECONDS_2017" => GPS_LEAP_SECONDS_2017,
-        _ => {
-            leap_seconds_str.parse::<i64>().expect(
-                &format!("Leap seconds string {} has invalid symbolic format", leap_seconds_str))
-        },
+        _ => match leap_seconds_str.parse::<i64>() {
+            Ok(leap_seconds) => leap_seconds,
+            Err(err) => return Err(exception::parse_error(
+                &format!("Leap seconds string {} has invalid symbolic format", leap_seconds_str),
+                leap_seconds_str).with_source(err))
+        }
     };
-    time::Duration::seconds(leap_seconds)
+    Ok(time::Duration::seconds(leap_seconds))
 }
 
 // converts a mission epoch string (either symbolic or ASD format) to seconds
-fn get_mission_epoch(mission_epoch_str: &str) -> time::Duration {
+fn parse_mission_epoch(mission_epoch_str: &str) ->
+    Result<time::Duration, exception::Exception> {
     let timespec = match mission_epoch_str {
         "TAI_MISSION_EPOCH" => {
             time::Timespec::new(TAI_MISSION_EPOCH_DELTA, 0)
@@ -89,21 +88,64 @@ fn get_mission_epoch(mission_epoch_str: &str) -> time::Duration {
         "GPS_MISSION_EPOCH" => {
             time::Timespec::new(GPS_MISSION_EPOCH_DELTA, 0)
          },
-        _ => {
-            asd_time::parse_time(mission_epoch_str).expect(
-                &format!("Mission epoch string {} has invalid symbolic format", mission_epoch_str))
-        },
+        _ => match asd_time::parse_time(mission_epoch_str) {
+            Ok(timespec) => timespec,
+            Err(err) => return Err(exception::wrap(
+                &format!("Mission epoch string {} has invalid symbolic format", mission_epoch_str),
+                err))
+        }
     };
     // convert Timespec to Duration
-    timespec - time::Timespec::new(0, 0)
+    Ok(timespec - time::Timespec::new(0, 0))
+}
+
+// checks a leap seconds config value
+fn check_leap_seconds_str(leap_seconds_str: &str) -> Result<(), exception::Exception> {
+    parse_leap_seconds(leap_seconds_str).map(|_| ())
+}
+
+// checks a mission epoch config value
+fn check_mission_epoch_str(mission_epoch_str: &str) -> Result<(), exception::Exception> {
+    parse_mission_epoch(mission_epoch_str).map(|_| ())
+}
+
+// computes the deltas from the config entries
+fn compute_deltas(config: &config::Config) ->
+    Result<Deltas, exception::Exception> {
+    Ok(Deltas {
+        ert_delta:
+            parse_mission_epoch(&config.get_string("TCO_ERT_MISSION_EPOCH_STR")?)? -
+            parse_leap_seconds(&config.get_string("TCO_ERT_LEAP_SECONDS_STR")?)?,
+        obt_delta:
+            parse_mission_epoch(&config.get_string("TCO_OBT_MISSION_EPOCH_STR")?)? -
+            parse_leap_seconds(&config.get_string("TCO_OBT_LEAP_SECONDS_STR")?)?
+    })
+}
+
+// computes the deltas from the global configuration and subscribes
+// for configuration changes, panics on invalid config entries
+fn init_deltas() -> Deltas {
+    config::subscribe(|config| {
+        let deltas = compute_deltas(config)?;
+        *DELTAS.write().unwrap() = deltas;
+        Ok(())
+    });
+    match config::get_config().and_then(|config| compute_deltas(&config)) {
+        Ok(deltas) => deltas,
+        Err(err) => panic!("{}", err)
+    }
 }
 
-// converts mission epoch string and leap seconds string
-// into mission epoch in seconds
-fn get_mission_epoch_with_leap_seconds_delta (
-    mission_epoch_str: &str,
-    leap_seconds_str: &str) -> time::Duration {
-    get_mission_epoch(mission_epoch_str) - get_leap_seconds(leap_seconds_str)
+// declares the config entries of this module for config::validate()
+pub fn register_config_keys() {
+    config::register_key("TCO_ERT_LEAP_SECONDS_STR", config::ValueType::Custom(check_leap_seconds_str),
+        None, "ERT leap seconds, numeric or GPS_LEAP_SECONDS_<year>");
+    config::register_key("TCO_ERT_MISSION_EPOCH_STR", config::ValueType::Custom(check_mission_epoch_str),
+        None, "ERT mission epoch, ASD time or <TAI|UNIX|GPS>_MISSION_EPOCH");
+    config::register_key("TCO_OBT_LEAP_SECONDS_STR", config::ValueType::Custom(check_leap_seconds_str),
+        None, "OBT leap seconds, numeric or GPS_LEAP_SECONDS_<year>");
+    config::register_key("TCO_OBT_MISSION_EPOCH_STR", config::ValueType::Custom(check_mission_epoch_str),
+        None, "OBT mission epoch, ASD time or <TAI|UNIX|GPS>_MISSION_EPOCH");
 }
 
 ///////////////
@@ -112,34 +154,34 @@ fn get_mission_epoch_with_leap_seconds_delta (
 
 // return the ERT delta
 pub fn get_ert_delta() -> time::Duration {
-    *ERT_DELTA
+    DELTAS.read().unwrap().ert_delta
 }
 
 // correlate the ERT mission epoch time to the local time
 pub fn correlate_from_ert_mission_epoch(epoch_time: time::Timespec) ->
     time::Timespec {
-    epoch_time + *ERT_DELTA
+    epoch_time + DELTAS.read().unwrap().ert_delta
 }
 
 // correlate the local time to ERT mission epoch time
 pub fn correlate_to_ert_mission_epoch(unix_time: time::Timespec) ->
     time::Timespec {
-    unix_time - *ERT_DELTA
+    unix_time - DELTAS.read().unwrap().ert_delta
 }
 
 // return the OBT delta
 pub fn get_obt_delta() -> time::Duration {
-    *OBT_DELTA
+    DELTAS.read().unwrap().obt_delta
 }
 
 // correlate the OBT mission epoch time to the local time
 pub fn correlate_from_obt_mission_epoch(epoch_time: time::Timespec) ->
     time::Timespec {
-    epoch_time + *OBT_DELTA
+    epoch_time + DELTAS.read().unwrap().obt_delta
 }
 
 // correlate the local time to OBT mission epoch time
 pub fn correlate_to_obt_mission_epoch(unix_time: time::Timespec) ->
     time::Timespec {
-    unix_time - *OBT_DELTA
+    unix_time - DELTAS.read().unwrap().obt_delta
 }
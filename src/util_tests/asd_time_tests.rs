@@ -12,9 +12,11 @@
 //*****************************************************************************
 // Utilities - ASD Time Conversions - Unit tests                              *
 //*****************************************************************************
+use std::error;
 use test_helpers::assert;
 use time;
 use util::asd_time;
+use util::exception;
 
 ///////////////
 // functions //
@@ -63,6 +65,13 @@ fn test_parse_error(time_str: &str) {
     let error_message = asd_time::parse_time(time_str).
         expect_err("expected error did not happen");
     println!("expected: {} for {}", error_message, time_str);
+    match *error_message.kind() {
+        exception::ErrorKind::ParseError {ref input} => assert::dump_string("input", input, time_str),
+        _ => panic!("unexpected error kind {:?}", error_message.kind())
+    }
+    if let Some(source) = error::Error::source(&error_message) {
+        println!("caused by: {}", source);
+    }
 }
 
 fn test_parse_ok(time_str: &str, expected_sec: i64, expected_nsec: i32) {
@@ -93,6 +102,10 @@ pub fn test() {
     test_format_ok_with_milli(test_time, "1980.006.00.00.00.123");
     test_format_ok_with_micro(test_time, "1980.006.00.00.00.123456");
     test_format_ok_with_nano(test_time, "1980.006.00.00.00.123456789");
+    assert::dump_string("time string", &asd_time::get_time_str_with_precision(test_time, 0), "1980.006.00.00.00");
+    assert::dump_string("time string", &asd_time::get_time_str_with_precision(test_time, 2), "1980.006.00.00.00.123");
+    assert::dump_string("time string", &asd_time::get_time_str_with_precision(test_time, 4), "1980.006.00.00.00.123456");
+    assert::dump_string("time string", &asd_time::get_time_str_with_precision(test_time, 12), "1980.006.00.00.00.123456789");
     // parse time tests
     test_parse_error("");
     test_parse_error("blablabla");
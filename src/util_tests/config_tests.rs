@@ -12,14 +12,283 @@
 //*****************************************************************************
 // Utilities - Configuration File Handling - Unit tests                       *
 //*****************************************************************************
-
+use std::env;
+use std::error;
+use std::fs;
+use std::sync;
+use std::time;
+use test_helpers::assert;
+use test_helpers::global_state;
 use util::config;
+use util::exception;
+use util::tco;
+
+///////////////
+// constants //
+///////////////
+
+const CONFIG_TEXT: &str = "
+# test configuration
+U8_ITEM=200
+I16_ITEM = -1000
+F64_ITEM=1.5
+STRING_ITEM=hello   # trailing comment
+MALFORMED_ITEM=12x
+";
+const SECTION_CONFIG_TEXT: &str = "
+TCO_ERT_LEAP_SECONDS_STR=0
+URL=http://host/path?a=1&b=2
+BASE_DIR=/data
+[sc1]
+DATA_DIR=${BASE_DIR}/${SPACECRAFT}
+SPACECRAFT=SC1
+APIDS = 100, 101 ,102
+ENABLED=yes
+TIMEOUT=500ms
+[sc2]
+ENABLED=0
+TIMEOUT=2s
+HOME_DIR=${CONFIG_TESTS_HOME}
+EMPTY_LIST=
+";
 
 ///////////////
 // functions //
 ///////////////
 
 pub fn test() {
+    // the tests modify the global configuration and environment variables
+    let _global_state_guard = global_state::lock();
     // checks if the config file can be read, otherwise panic
     config::force_config_items_read();
+    // configuration object without panics
+    let config1 = config::Config::new_from_str(CONFIG_TEXT).unwrap();
+    assert::dump_u8("U8_ITEM", config1.get_u8("U8_ITEM").unwrap(), 200);
+    assert::dump_i64("I16_ITEM", config1.get_i16("I16_ITEM").unwrap() as i64, -1000);
+    assert::dump_f64("F64_ITEM", config1.get_f64("F64_ITEM").unwrap(), 1.5);
+    assert::dump_string("STRING_ITEM", &config1.get_string("STRING_ITEM").unwrap(), "hello");
+    assert::dump_u32("DEFAULT_ITEM", config1.get_or("DEFAULT_ITEM", 17_u32).unwrap(), 17);
+    assert::dump_u32("U8_ITEM", config1.get_or("U8_ITEM", 17_u32).unwrap(), 200);
+    let error_message = config1.get_u8("MISSING_ITEM").
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = config1.get_u8("I16_ITEM").
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = config1.get_or("MALFORMED_ITEM", 0_u32).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    match *error_message.kind() {
+        exception::ErrorKind::ParseError {ref input} => assert::dump_string("input", input, "12x"),
+        _ => panic!("unexpected error kind {:?}", error_message.kind())
+    }
+    let error_message = config::Config::new_from_str("NO_VALUE").
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = config::Config::new_from_file("no_such_file.txt").
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    if error::Error::source(&error_message).is_none() {
+        panic!("missing I/O error source");
+    }
+    // sections, includes and value types
+    env::set_var("CONFIG_TESTS_HOME", "/home/sc2");
+    let config3 = config::Config::new_from_str(SECTION_CONFIG_TEXT).unwrap();
+    assert::dump_string("TCO_ERT_LEAP_SECONDS_STR",
+        &config3.get_string("TCO_ERT_LEAP_SECONDS_STR").unwrap(), "0");
+    assert::dump_string("URL", &config3.get_string("URL").unwrap(), "http://host/path?a=1&b=2");
+    assert::dump_string("sc1.DATA_DIR", &config3.get_string("sc1.DATA_DIR").unwrap(), "/data/SC1");
+    assert::dump_string("sc2.HOME_DIR", &config3.get_string("sc2.HOME_DIR").unwrap(), "/home/sc2");
+    let apids = config3.get_list("sc1.APIDS").unwrap();
+    assert::dump_string("sc1.APIDS", &apids.join("|"), "100|101|102");
+    assert::dump_usize("sc2.EMPTY_LIST", config3.get_list("sc2.EMPTY_LIST").unwrap().len(), 0);
+    if !config3.get_bool("sc1.ENABLED").unwrap() || config3.get_bool("sc2.ENABLED").unwrap() {
+        panic!("unexpected boolean values");
+    }
+    if config3.get_duration("sc1.TIMEOUT").unwrap() != time::Duration::from_millis(500) ||
+       config3.get_duration("sc2.TIMEOUT").unwrap() != time::Duration::from_secs(2) {
+        panic!("unexpected duration values");
+    }
+    if config3.contains("TIMEOUT") {
+        panic!("section item without section prefix");
+    }
+    let error_message = config3.get_bool("URL").
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = config3.get_duration("BASE_DIR").
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = config::Config::new_from_str("A=${UNDEFINED_CONFIG_TESTS_VAR}").
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = config::Config::new_from_str("A=${B}\nB=${A}").
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // include files are relative to the including file
+    let directory = env::temp_dir().join("config_tests");
+    fs::create_dir_all(&directory).unwrap();
+    fs::write(directory.join("main.txt"), "[sc1]\ninclude included.txt\nLOCAL=1\n").unwrap();
+    fs::write(directory.join("included.txt"), "INCLUDED=2\n[sc2]\nOTHER=3\n").unwrap();
+    fs::write(directory.join("recursive.txt"), "include recursive.txt\n").unwrap();
+    let config4 = config::Config::new_from_file(directory.join("main.txt").to_str().unwrap()).unwrap();
+    assert::dump_u8("sc1.INCLUDED", config4.get_u8("sc1.INCLUDED").unwrap(), 2);
+    assert::dump_u8("sc2.OTHER", config4.get_u8("sc2.OTHER").unwrap(), 3);
+    assert::dump_u8("sc1.LOCAL", config4.get_u8("sc1.LOCAL").unwrap(), 1);
+    let error_message = config::Config::new_from_file(directory.join("recursive.txt").to_str().unwrap()).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // validation against declared keys
+    let config_keys = vec![
+        config_key("U8_ITEM", config::ValueType::Unsigned, None),
+        config_key("I16_ITEM", config::ValueType::Bool, None),
+        config_key("F64_ITEM", config::ValueType::Float, None),
+        config_key("STRING_ITEM", config::ValueType::Custom(check_hello), None),
+        config_key("MALFORMED_ITEM", config::ValueType::Duration, Some("1s")),
+        config_key("DEFAULT_ITEM", config::ValueType::Duration, Some("1s")),
+        config_key("MISSING_ITEM", config::ValueType::String, None)];
+    let report = config1.validate(&config_keys);
+    print!("{}", report);
+    assert::dump_usize("missing", report.missing.len(), 1);
+    assert::dump_string("missing", &report.missing[0].0, "MISSING_ITEM");
+    assert::dump_usize("malformed", report.malformed.len(), 2);
+    assert::dump_string("malformed", &report.malformed[0].0, "I16_ITEM");
+    assert::dump_string("malformed", &report.malformed[1].0, "MALFORMED_ITEM");
+    assert::dump_usize("unknown", report.unknown.len(), 0);
+    let report = config1.validate(&config_keys[..2]);
+    assert::dump_string("unknown", &report.unknown.join("|"), "F64_ITEM|MALFORMED_ITEM|STRING_ITEM");
+    // registered defaults are used for missing entries
+    config::register_key("CONFIG_TESTS_DEFAULT_ITEM", config::ValueType::Unsigned, Some("42"), "test item");
+    assert::dump_u32("CONFIG_TESTS_DEFAULT_ITEM", config1.get_u32("CONFIG_TESTS_DEFAULT_ITEM").unwrap(), 42);
+    // the TCO entries of the global configuration are valid
+    tco::register_config_keys();
+    let report = config::validate().unwrap();
+    print!("{}", report);
+    if !report.missing.is_empty() || !report.malformed.is_empty() {
+        panic!("invalid TCO configuration");
+    }
+    // command line options and value origins
+    let args = ["--check-config", "--set", "A=1", "--config=main.txt", "--set=B=x=y", "--set", "A=2"];
+    let options = config::CommandLineOptions::new_from_args(args.iter().map(|arg| arg.to_string())).unwrap();
+    assert::dump_string("config_file_name", &options.config_file_name, "main.txt");
+    assert::dump_usize("values", options.values.len(), 3);
+    let args = ["config_file", "legacy.txt"];
+    let options = config::CommandLineOptions::new_from_args(args.iter().map(|arg| arg.to_string())).unwrap();
+    assert::dump_string("config_file_name", &options.config_file_name, "legacy.txt");
+    let error_message = config::CommandLineOptions::new_from_args(vec!["--set".to_string()]).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = config::CommandLineOptions::new_from_args(vec!["--set=A".to_string()]).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    env::set_var("CONFIG_TESTS_ENV_ITEM", "env");
+    env::set_var("CONFIG_TESTS_CMD_ITEM", "env");
+    let mut config6 = config::Config::new_from_str(
+        "CONFIG_TESTS_FILE_ITEM=file\nCONFIG_TESTS_ENV_ITEM=file\nCONFIG_TESTS_CMD_ITEM=file").unwrap();
+    config6.apply_environment();
+    let args = ["--set", "CONFIG_TESTS_CMD_ITEM=cmd", "--set", "CONFIG_TESTS_NEW_ITEM=cmd"];
+    config6.apply_command_line(&config::CommandLineOptions::new_from_args(
+        args.iter().map(|arg| arg.to_string())).unwrap());
+    let dump = config6.dump_effective_str();
+    print!("{}", dump);
+    assert::dump_string("CONFIG_TESTS_FILE_ITEM", &config6.get_string("CONFIG_TESTS_FILE_ITEM").unwrap(), "file");
+    assert::dump_string("CONFIG_TESTS_ENV_ITEM", &config6.get_string("CONFIG_TESTS_ENV_ITEM").unwrap(), "env");
+    assert::dump_string("CONFIG_TESTS_CMD_ITEM", &config6.get_string("CONFIG_TESTS_CMD_ITEM").unwrap(), "cmd");
+    assert::dump_string("CONFIG_TESTS_NEW_ITEM", &config6.get_string("CONFIG_TESTS_NEW_ITEM").unwrap(), "cmd");
+    if config6.get_origin("CONFIG_TESTS_FILE_ITEM") != Some(config::ValueOrigin::File("string".to_string())) ||
+       config6.get_origin("CONFIG_TESTS_ENV_ITEM") != Some(config::ValueOrigin::Environment) ||
+       config6.get_origin("CONFIG_TESTS_CMD_ITEM") != Some(config::ValueOrigin::CommandLine) ||
+       config6.get_origin("CONFIG_TESTS_DEFAULT_ITEM") != Some(config::ValueOrigin::Default) ||
+       config6.get_origin("CONFIG_TESTS_MISSING_ITEM").is_some() {
+        panic!("unexpected value origins");
+    }
+    // interpolation after all layers are merged
+    let mut config8 = config::Config::new_from_str("A=${B}/a\nB=file").unwrap();
+    assert::dump_string("A", &config8.get_string("A").unwrap(), "file/a");
+    let args = ["--set", "B=cmd", "--set", "C=${A}/c"];
+    config8.apply_command_line(&config::CommandLineOptions::new_from_args(
+        args.iter().map(|arg| arg.to_string())).unwrap());
+    config8.interpolate().unwrap();
+    assert::dump_string("A", &config8.get_string("A").unwrap(), "cmd/a");
+    assert::dump_string("C", &config8.get_string("C").unwrap(), "cmd/a/c");
+    if !dump.contains("CONFIG_TESTS_DEFAULT_ITEM = 42   [default]\n") ||
+       !dump.contains("CONFIG_TESTS_ENV_ITEM = env   [environment]\n") {
+        panic!("unexpected effective configuration");
+    }
+    // the global configuration is available as object too
+    let config2 = config::get_config().unwrap();
+    if !config2.contains("TCO_ERT_LEAP_SECONDS_STR") {
+        panic!("TCO_ERT_LEAP_SECONDS_STR not found");
+    }
+    assert::dump_string("TCO_ERT_LEAP_SECONDS_STR",
+        &config::get_config_value_as_string("TCO_ERT_LEAP_SECONDS_STR"),
+        &config2.get_string("TCO_ERT_LEAP_SECONDS_STR").unwrap());
+    // replacing the configuration notifies the subscribers
+    let ert_delta = tco::get_ert_delta().num_seconds();
+    let notifications = sync::Arc::new(sync::atomic::AtomicUsize::new(0));
+    let subscriber_notifications = notifications.clone();
+    let subscription_id = config::subscribe(move |config| {
+        subscriber_notifications.fetch_add(1, sync::atomic::Ordering::SeqCst);
+        if config.contains("CONFIG_TESTS_REJECT") {
+            return Err(exception::raise("configuration rejected"));
+        }
+        Ok(())
+    });
+    let mut config5 = (*config2).clone();
+    config5.set_value("TCO_ERT_LEAP_SECONDS_STR", "GPS_LEAP_SECONDS_2017");
+    config::set_config(config5.clone()).unwrap();
+    assert::dump_string("TCO_ERT_LEAP_SECONDS_STR",
+        &config::get_config_value_as_string("TCO_ERT_LEAP_SECONDS_STR"), "GPS_LEAP_SECONDS_2017");
+    assert::dump_i64("ERT delta", tco::get_ert_delta().num_seconds(), ert_delta - 18);
+    config5.set_value("CONFIG_TESTS_REJECT", "1");
+    let error_message = config::set_config(config5).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // a rejected configuration is not applied, the subscribers
+    // are notified again with the previous configuration
+    assert::dump_usize("notifications", notifications.load(sync::atomic::Ordering::SeqCst), 3);
+    if config::get_config().unwrap().contains("CONFIG_TESTS_REJECT") {
+        panic!("rejected configuration is applied");
+    }
+    let mut config7 = (*config2).clone();
+    config7.set_value("TCO_ERT_MISSION_EPOCH_STR", "NO_MISSION_EPOCH");
+    let error_message = config::set_config(config7).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    assert::dump_usize("notifications", notifications.load(sync::atomic::Ordering::SeqCst), 5);
+    assert::dump_string("TCO_ERT_MISSION_EPOCH_STR",
+        &config::get_config_value_as_string("TCO_ERT_MISSION_EPOCH_STR"),
+        &config2.get_string("TCO_ERT_MISSION_EPOCH_STR").unwrap());
+    assert::dump_string("TCO_ERT_LEAP_SECONDS_STR",
+        &config::get_config_name_value_pairs()["TCO_ERT_LEAP_SECONDS_STR"], "GPS_LEAP_SECONDS_2017");
+    assert::dump_i64("ERT delta", tco::get_ert_delta().num_seconds(), ert_delta - 18);
+    // reload restores the configuration from the file
+    config::reload().unwrap();
+    assert::dump_i64("ERT delta", tco::get_ert_delta().num_seconds(), ert_delta);
+    assert::dump_usize("notifications", notifications.load(sync::atomic::Ordering::SeqCst), 6);
+    assert::dump_string("TCO_ERT_LEAP_SECONDS_STR",
+        &config::get_config_name_value_pairs()["TCO_ERT_LEAP_SECONDS_STR"],
+        &config2.get_string("TCO_ERT_LEAP_SECONDS_STR").unwrap());
+    config::unsubscribe(subscription_id);
+    config::reload().unwrap();
+    assert::dump_usize("notifications", notifications.load(sync::atomic::Ordering::SeqCst), 6);
+}
+
+// declaration for the validation tests
+fn config_key(name: &str, value_type: config::ValueType, default_value: Option<&str>) ->
+    config::ConfigKey {
+    config::ConfigKey {
+        name: name.to_string(),
+        value_type,
+        default_value: default_value.map(|value| value.to_string()),
+        description: format!("test item {}", name)
+    }
+}
+
+// custom check for the validation tests
+fn check_hello(value: &str) -> Result<(), exception::Exception> {
+    if value == "hello" {
+        Ok(())
+    } else {
+        Err(exception::parse_error("value is not hello", value))
+    }
 }
@@ -12,6 +12,7 @@
 //*****************************************************************************
 // Utilities - Cyclic Redundancy Check (CRC) - Unit tests                     *
 //*****************************************************************************
+use std::hash::Hasher;
 use test_helpers::assert;
 use util::crc;
 
@@ -159,7 +160,107 @@ fn test_crc16_operation() {
     assert::dump_u16("crc", crc, expected_crc);
 }
 
+fn test_crc16_implementations() {
+    // the table driven implementations must be identical to the bitwise one
+    let patterns: [&[u8]; 3] = [&TC_PACKET_01, &TC_FRAME_01, &TC_FRAME_02];
+    for pkt in patterns.iter() {
+        let pkt_size = pkt.len();
+        let expected_crc = crc::calculate16_bitwise(pkt, pkt_size - 2);
+        assert::dump_u16("crc table", crc::calculate16_table(pkt, pkt_size - 2), expected_crc);
+        assert::dump_u16("crc slice8", crc::calculate16_slice8(pkt, pkt_size - 2), expected_crc);
+    }
+    // all sizes around the 8 byte steps
+    let bytes: Vec<u8> = (0..64_u32).map(|i| (i * 157 + 13) as u8).collect();
+    for size in 0..bytes.len() {
+        let expected_crc = crc::calculate16_bitwise(&bytes, size);
+        if crc::calculate16_table(&bytes, size) != expected_crc ||
+           crc::calculate16_slice8(&bytes, size) != expected_crc {
+            panic!("CRC-16 mismatch for size {}", size);
+        }
+    }
+}
+
+fn test_crc_definitions() {
+    let definitions = [
+        crc::CRC8_SPW_RMAP,
+        crc::CRC16_CCITT_FALSE,
+        crc::CRC16_KERMIT,
+        crc::CRC16_X25,
+        crc::CRC32_ISO_HDLC,
+        crc::CRC32_MPEG2,
+        crc::CRC32_C];
+    for definition in definitions.iter() {
+        let crc = crc::Crc::new(*definition);
+        assert::dump_u64(definition.name, crc.checksum(b"123456789"), definition.check);
+    }
+    // the generic CRCs are compatible with the specific ones
+    let crc8 = crc::Crc::new(crc::CRC8_SPW_RMAP);
+    assert::dump_u64("crc8", crc8.checksum(&SPW_RMAP_HEADER1), SPW_RMAP_HEADER1_CRC as u64);
+    assert::dump_u64("crc8", crc8.checksum(&SPW_RMAP_DATA1), SPW_RMAP_DATA1_CRC as u64);
+    let crc16 = crc::Crc::new(crc::CRC16_CCITT_FALSE);
+    let pkt = TC_PACKET_01;
+    let pkt_size = pkt.len();
+    assert::dump_u64("crc16", crc16.checksum(&pkt[..pkt_size - 2]),
+        crc::calculate16(&pkt, pkt_size - 2) as u64);
+}
+
+fn test_crc_streaming() {
+    // CRC-16 over chunks of different sizes
+    let pkt = TC_FRAME_01;
+    let pkt_size = pkt.len();
+    let expected_crc = crc::calculate16(&pkt, pkt_size - 2);
+    for chunk_size in [1, 3, 8, 13, 100].iter() {
+        let mut crc16 = crc::Crc16::new();
+        for chunk in pkt[..pkt_size - 2].chunks(*chunk_size) {
+            crc16.update(chunk);
+        }
+        assert::dump_u16("crc16", crc16.finalize(), expected_crc);
+    }
+    // continue from a previously computed value
+    let intermediate_crc = crc::calculate16(&pkt, 100);
+    let mut crc16 = crc::Crc16::new_with_value(intermediate_crc);
+    crc16.update(&pkt[100..pkt_size - 2]);
+    assert::dump_u16("crc16", crc16.finalize(), expected_crc);
+    // Hasher ergonomics
+    let mut crc16 = crc::Crc16::default();
+    crc16.write(&pkt[..pkt_size - 2]);
+    assert::dump_u64("crc16", crc16.finish(), expected_crc as u64);
+    crc16.reset();
+    assert::dump_u16("crc16", crc16.finalize(), crc::CRC16_INIT);
+    // CRC-8 over chunks
+    let mut crc8 = crc::Crc8::new();
+    crc8.update(&SPW_RMAP_DATA1[..5]);
+    let mut crc8 = crc::Crc8::new_with_value(crc8.finalize());
+    crc8.update(&SPW_RMAP_DATA1[5..]);
+    assert::dump_u8("crc8", crc8.finalize(), SPW_RMAP_DATA1_CRC);
+}
+
+fn test_crc32_and_iso_checksum() {
+    let bytes = b"123456789";
+    assert::dump_u32("crc32 ieee", crc::calculate32_ieee(bytes, bytes.len()), 0xcbf43926);
+    assert::dump_u32("crc32 ccsds", crc::calculate32_ccsds(bytes, bytes.len()), 0x0376e6e7);
+    // ISO checksum
+    assert::dump_u16("iso", crc::calculate_iso(&[0x01, 0x02], 2), 0xf804);
+    let pkt = TC_PACKET_01;
+    let pkt_size = pkt.len();
+    let mut bytes = pkt.to_vec();
+    let checksum = crc::calculate_iso(&bytes, pkt_size - 2);
+    bytes[pkt_size - 2] = (checksum >> 8) as u8;
+    bytes[pkt_size - 1] = checksum as u8;
+    if !crc::check_iso(&bytes, pkt_size) {
+        panic!("ISO checksum not valid");
+    }
+    bytes[10] ^= 0x01;
+    if crc::check_iso(&bytes, pkt_size) {
+        panic!("ISO checksum of corrupted data is valid");
+    }
+}
+
 pub fn test() {
     test_crc8_operation();
     test_crc16_operation();
+    test_crc16_implementations();
+    test_crc_definitions();
+    test_crc_streaming();
+    test_crc32_and_iso_checksum();
 }
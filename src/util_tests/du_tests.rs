@@ -15,6 +15,7 @@
 use test_helpers::assert;
 use util::du;
 use util::du::DUintf;
+use util::exception;
 
 def_unsigned_accessor!(UINT_ACC1, 0, 1);
 def_unsigned_accessor!(UINT_ACC2, 0, 2);
@@ -30,14 +31,58 @@ def_big_unsigned_accessor!(BUINT_ACC6, 0, 6);
 def_big_unsigned_accessor!(BUINT_ACC7, 0, 7);
 def_big_unsigned_accessor!(BUINT_ACC8, 0, 8);
 
+def_signed_accessor!(INT_ACC1, 0, 1);
+def_signed_accessor!(INT_ACC2, 0, 2);
+def_signed_accessor!(INT_ACC3, 0, 3);
+def_signed_accessor!(INT_ACC4, 0, 4);
+
+def_big_signed_accessor!(BINT_ACC1, 0, 1);
+def_big_signed_accessor!(BINT_ACC3, 0, 3);
+def_big_signed_accessor!(BINT_ACC5, 0, 5);
+def_big_signed_accessor!(BINT_ACC8, 0, 8);
+
+def_float_accessor!(FLOAT_ACC4, 0, 4);
+def_float_accessor!(FLOAT_ACC8, 0, 8);
+
+def_mil_float_accessor!(MIL_FLOAT_ACC4, 0, 4);
+def_mil_float_accessor!(MIL_FLOAT_ACC6, 0, 6);
+
+def_unsigned_le_accessor!(UINT_LE_ACC2, 1, 2);
+def_unsigned_le_accessor!(UINT_LE_ACC4, 0, 4);
+
+def_big_unsigned_le_accessor!(BUINT_LE_ACC8, 0, 8);
+
+def_signed_le_accessor!(INT_LE_ACC3, 1, 3);
+
+def_big_signed_le_accessor!(BINT_LE_ACC6, 2, 6);
+
+def_float_le_accessor!(FLOAT_LE_ACC4, 0, 4);
+def_float_le_accessor!(FLOAT_LE_ACC8, 0, 8);
+
 def_bit_accessor!(BIT_ACC1,  4,  1);
 def_bit_accessor!(BIT_ACC2,  5,  3);
 def_bit_accessor!(BIT_ACC3, 12,  8);
 def_bit_accessor!(BIT_ACC4, 20, 16);
 def_bit_accessor!(BIT_ACC5, 20, 32);
 
+def_big_bit_accessor!(BBIT_ACC1,  7, 64);
+def_big_bit_accessor!(BBIT_ACC2, 12, 40);
+
+def_signed_bit_accessor!(SBIT_ACC1,  4, 12);
+def_signed_bit_accessor!(SBIT_ACC2, 19,  1);
+
+def_big_signed_bit_accessor!(BSBIT_ACC1,  3, 64);
+def_big_signed_bit_accessor!(BSBIT_ACC2, 16, 36);
+
 def_byte_accessor!(BYTE_ACC1, 5, 4);
 
+def_string_accessor!(STR_ACC1, 0, 6, du::StringPadding::Space);
+def_string_accessor!(STR_ACC2, 6, 4, du::StringPadding::Null);
+def_string_accessor!(STR_ACC3, 0, 3, du::StringPadding::NoPadding);
+
+def_variable_octets_accessor!(VOCT_ACC1, 0, 1);
+def_variable_octets_accessor!(VOCT_ACC2, 4, 2);
+
 pub fn assert_dump_du(val_name: &str, val: &du::DU, expected: &str) {
     println!("{} = {}", val_name, val.dump_str());
     assert_eq!(val.dump_str(), expected);
@@ -205,9 +250,7 @@ pub fn test() {
     du9.resize(0x20);
     assert_dump_du("du9", &du9, "
 0000 40 31 32 33 34 35 36 37 38 39 20 68 69 68 69 68 @123456789 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
 
     let mut val = du9.get_unsigned_acc(UINT_ACC1).unwrap();
     assert::dump_u32("val", val, 64);
@@ -221,27 +264,19 @@ pub fn test() {
     du9.set_unsigned_acc(UINT_ACC1, 1).unwrap();
     assert_dump_du("du9", &du9, "
 0000 01 31 32 33 34 35 36 37 38 39 20 68 69 68 69 68 .123456789 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
     du9.set_unsigned_acc(UINT_ACC2, 513).unwrap();
     assert_dump_du("du9", &du9, "
 0000 02 01 32 33 34 35 36 37 38 39 20 68 69 68 69 68 ..23456789 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
     du9.set_unsigned_acc(UINT_ACC3, 197121).unwrap();
     assert_dump_du("du9", &du9, "
 0000 03 02 01 33 34 35 36 37 38 39 20 68 69 68 69 68 ...3456789 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
     du9.set_unsigned_acc(UINT_ACC4, 67305985).unwrap();
     assert_dump_du("du9", &du9, "
 0000 04 03 02 01 34 35 36 37 38 39 20 68 69 68 69 68 ....456789 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
 
     let mut bval = du9.get_big_unsigned_acc(BUINT_ACC1).unwrap();
     assert::dump_u64("bval", bval, 4);
@@ -263,51 +298,35 @@ pub fn test() {
     du9.set_big_unsigned_acc(BUINT_ACC1, 1).unwrap();
     assert_dump_du("du9", &du9, "
 0000 01 03 02 01 34 35 36 37 38 39 20 68 69 68 69 68 ....456789 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
     du9.set_big_unsigned_acc(BUINT_ACC2, 513).unwrap();
     assert_dump_du("du9", &du9, "
 0000 02 01 02 01 34 35 36 37 38 39 20 68 69 68 69 68 ....456789 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
     du9.set_big_unsigned_acc(BUINT_ACC3, 197121).unwrap();
     assert_dump_du("du9", &du9, "
 0000 03 02 01 01 34 35 36 37 38 39 20 68 69 68 69 68 ....456789 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
     du9.set_big_unsigned_acc(BUINT_ACC4, 67305985).unwrap();
     assert_dump_du("du9", &du9, "
 0000 04 03 02 01 34 35 36 37 38 39 20 68 69 68 69 68 ....456789 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
     du9.set_big_unsigned_acc(BUINT_ACC5, 21542142465).unwrap();
     assert_dump_du("du9", &du9, "
 0000 05 04 03 02 01 35 36 37 38 39 20 68 69 68 69 68 .....56789 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
     du9.set_big_unsigned_acc(BUINT_ACC6, 6618611909121).unwrap();
     assert_dump_du("du9", &du9, "
 0000 06 05 04 03 02 01 36 37 38 39 20 68 69 68 69 68 ......6789 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
     du9.set_big_unsigned_acc(BUINT_ACC7, 1976943448883713).unwrap();
     assert_dump_du("du9", &du9, "
 0000 07 06 05 04 03 02 01 37 38 39 20 68 69 68 69 68 .......789 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
     du9.set_big_unsigned_acc(BUINT_ACC8, 578437695752307201).unwrap();
     assert_dump_du("du9", &du9, "
 0000 08 07 06 05 04 03 02 01 38 39 20 68 69 68 69 68 ........89 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
 
     // temporary force a proper contents in the buffer
     du9[0] = 0x04;
@@ -333,33 +352,23 @@ pub fn test() {
     du9.set_bits_acc(BIT_ACC1, 1).unwrap();
     assert_dump_du("du9", &du9, "
 0000 0c 03 02 01 34 35 36 37 38 39 20 68 69 68 69 68 ....456789 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
     du9.set_bits_acc(BIT_ACC2, 3).unwrap();
     assert_dump_du("du9", &du9, "
 0000 0b 03 02 01 34 35 36 37 38 39 20 68 69 68 69 68 ....456789 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
     du9.set_bits_acc(BIT_ACC3, 171).unwrap();
     assert_dump_du("du9", &du9, "
 0000 0b 0a b2 01 34 35 36 37 38 39 20 68 69 68 69 68 ....456789 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
     du9.set_bits_acc(BIT_ACC4, 39030).unwrap();
     assert_dump_du("du9", &du9, "
 0000 0b 0a b9 87 64 35 36 37 38 39 20 68 69 68 69 68 ....d56789 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
     du9.set_bits_acc(BIT_ACC5, 305419896).unwrap();
     assert_dump_du("du9", &du9, "
 0000 0b 0a b1 23 45 67 86 37 38 39 20 68 69 68 69 68 ...#Eg.789 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
 
     // sub-scope to avoid life cycle conflict (read-only + read-write)
     {
@@ -372,7 +381,497 @@ pub fn test() {
     du9.set_bytes_acc(BYTE_ACC1, "ABCD".as_bytes()).unwrap();
     assert_dump_du("du9", &du9, "
 0000 0b 0a b1 23 45 41 42 43 44 39 20 68 69 68 69 68 ...#EABCD9 hihih
-0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........
-0020 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ................
-0030 00 00 00 00 00 00 00 00                         ........");
+0010 69 20 68 75 68 75 68 75 00 00 00 00 00 00 00 00 i huhuhu........");
+
+    // signed integer access
+    let mut du10 = du::DU::new_alloc(8);
+    du10.set_signed_acc(INT_ACC1, -1).unwrap();
+    assert_dump_du("du10", &du10, "
+0000 ff 00 00 00 00 00 00 00                         ........");
+    let mut ival = du10.get_signed_acc(INT_ACC1).unwrap();
+    assert::dump_i32("ival", ival, -1);
+    ival = du10.get_signed_acc(INT_ACC2).unwrap();
+    assert::dump_i32("ival", ival, -256);
+    du10.set_signed_acc(INT_ACC2, -2).unwrap();
+    assert_dump_du("du10", &du10, "
+0000 ff fe 00 00 00 00 00 00                         ........");
+    ival = du10.get_signed_acc(INT_ACC2).unwrap();
+    assert::dump_i32("ival", ival, -2);
+    du10.set_signed_acc(INT_ACC3, -8388608).unwrap();
+    assert_dump_du("du10", &du10, "
+0000 80 00 00 00 00 00 00 00                         ........");
+    ival = du10.get_signed_acc(INT_ACC3).unwrap();
+    assert::dump_i32("ival", ival, -8388608);
+    du10.set_signed_acc(INT_ACC3, 1193046).unwrap();
+    assert_dump_du("du10", &du10, "
+0000 12 34 56 00 00 00 00 00                         .4V.....");
+    ival = du10.get_signed_acc(INT_ACC3).unwrap();
+    assert::dump_i32("ival", ival, 1193046);
+    du10.set_signed_acc(INT_ACC4, -2147483648).unwrap();
+    assert_dump_du("du10", &du10, "
+0000 80 00 00 00 00 00 00 00                         ........");
+    ival = du10.get_signed_acc(INT_ACC4).unwrap();
+    assert::dump_i32("ival", ival, -2147483648);
+    du10.set_signed_acc(INT_ACC1, 127).unwrap();
+    ival = du10.get_signed_acc(INT_ACC1).unwrap();
+    assert::dump_i32("ival", ival, 127);
+    let error_message = du10.set_signed_acc(INT_ACC1, 128).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = du10.set_signed_acc(INT_ACC1, -129).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = du10.set_signed(6, 4, 0).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+
+    // big signed integer access
+    du10.set_big_signed_acc(BINT_ACC8, -2).unwrap();
+    assert_dump_du("du10", &du10, "
+0000 ff ff ff ff ff ff ff fe                         ........");
+    let mut bival = du10.get_big_signed_acc(BINT_ACC8).unwrap();
+    assert::dump_i64("bival", bival, -2);
+    bival = du10.get_big_signed_acc(BINT_ACC1).unwrap();
+    assert::dump_i64("bival", bival, -1);
+    du10.set_big_signed_acc(BINT_ACC5, -549755813888).unwrap();
+    assert_dump_du("du10", &du10, "
+0000 80 00 00 00 00 ff ff fe                         ........");
+    bival = du10.get_big_signed_acc(BINT_ACC5).unwrap();
+    assert::dump_i64("bival", bival, -549755813888);
+    bival = du10.get_big_signed_acc(BINT_ACC3).unwrap();
+    assert::dump_i64("bival", bival, -8388608);
+    du10.set_big_signed_acc(BINT_ACC8, 81985529216486895).unwrap();
+    assert_dump_du("du10", &du10, "
+0000 01 23 45 67 89 ab cd ef                         .#Eg....");
+    bival = du10.get_big_signed_acc(BINT_ACC8).unwrap();
+    assert::dump_i64("bival", bival, 81985529216486895);
+    let error_message = du10.set_big_signed_acc(BINT_ACC5, 549755813888).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+
+    // IEEE floating point access
+    let mut du11 = du::DU::new_alloc(8);
+    du11.set_float_acc(FLOAT_ACC4, 1.5).unwrap();
+    assert_dump_du("du11", &du11, "
+0000 3f c0 00 00 00 00 00 00                         ?.......");
+    let mut fval = du11.get_float_acc(FLOAT_ACC4).unwrap();
+    assert::dump_f64("fval", fval, 1.5);
+    du11.set_float_acc(FLOAT_ACC4, -0.25).unwrap();
+    assert_dump_du("du11", &du11, "
+0000 be 80 00 00 00 00 00 00                         ........");
+    fval = du11.get_float_acc(FLOAT_ACC4).unwrap();
+    assert::dump_f64("fval", fval, -0.25);
+    let error = du11.set_float_acc(FLOAT_ACC4, 1.0e39).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error);
+    if let exception::ErrorKind::ValueOutOfRange {..} = *error.kind() {} else {
+        panic!("unexpected error kind {:?}", error.kind());
+    }
+    du11.set_float_acc(FLOAT_ACC8, 1.5).unwrap();
+    assert_dump_du("du11", &du11, "
+0000 3f f8 00 00 00 00 00 00                         ?.......");
+    fval = du11.get_float_acc(FLOAT_ACC8).unwrap();
+    assert::dump_f64("fval", fval, 1.5);
+    du11.set_float_acc(FLOAT_ACC8, -1.0e39).unwrap();
+    fval = du11.get_float_acc(FLOAT_ACC8).unwrap();
+    assert::dump_f64("fval", fval, -1.0e39);
+    let error_message = du11.set_float(0, 5, 1.0).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+
+    // MIL-STD-1750A floating point access (values from the standard)
+    let mut du12 = du::DU::new_alloc(8);
+    du12.set_mil_float_acc(MIL_FLOAT_ACC4, 10.0).unwrap();
+    assert_dump_du("du12", &du12, "
+0000 50 00 00 04 00 00 00 00                         P.......");
+    fval = du12.get_mil_float_acc(MIL_FLOAT_ACC4).unwrap();
+    assert::dump_f64("fval", fval, 10.0);
+    du12.set_mil_float_acc(MIL_FLOAT_ACC4, 1.0).unwrap();
+    assert_dump_du("du12", &du12, "
+0000 40 00 00 01 00 00 00 00                         @.......");
+    du12.set_mil_float_acc(MIL_FLOAT_ACC4, 0.25).unwrap();
+    assert_dump_du("du12", &du12, "
+0000 40 00 00 ff 00 00 00 00                         @.......");
+    du12.set_mil_float_acc(MIL_FLOAT_ACC4, -1.0).unwrap();
+    assert_dump_du("du12", &du12, "
+0000 80 00 00 00 00 00 00 00                         ........");
+    fval = du12.get_mil_float_acc(MIL_FLOAT_ACC4).unwrap();
+    assert::dump_f64("fval", fval, -1.0);
+    du12.set_mil_float_acc(MIL_FLOAT_ACC4, -0.5).unwrap();
+    assert_dump_du("du12", &du12, "
+0000 80 00 00 ff 00 00 00 00                         ........");
+    fval = du12.get_mil_float_acc(MIL_FLOAT_ACC4).unwrap();
+    assert::dump_f64("fval", fval, -0.5);
+    du12.set_mil_float_acc(MIL_FLOAT_ACC4, -12.0).unwrap();
+    assert_dump_du("du12", &du12, "
+0000 a0 00 00 04 00 00 00 00                         ........");
+    du12.set_mil_float_acc(MIL_FLOAT_ACC4, 0.0).unwrap();
+    assert_dump_du("du12", &du12, "
+0000 00 00 00 00 00 00 00 00                         ........");
+    // rounding of the mantissa
+    du12.set_mil_float_acc(MIL_FLOAT_ACC4, 0.99999999).unwrap();
+    assert_dump_du("du12", &du12, "
+0000 40 00 00 01 00 00 00 00                         @.......");
+    du12.set_mil_float_acc(MIL_FLOAT_ACC4, 1.0e-40).unwrap();
+    assert_dump_du("du12", &du12, "
+0000 00 00 00 00 00 00 00 00                         ........");
+    let error_message = du12.set_mil_float_acc(MIL_FLOAT_ACC4, 1.0e39).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error = du12.set_mil_float_acc(MIL_FLOAT_ACC4, f64::INFINITY).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error);
+    if let exception::ErrorKind::ValueOutOfRange {..} = *error.kind() {} else {
+        panic!("unexpected error kind {:?}", error.kind());
+    }
+    du12.set_mil_float_acc(MIL_FLOAT_ACC6, 0.5).unwrap();
+    assert_dump_du("du12", &du12, "
+0000 40 00 00 00 00 00 00 00                         @.......");
+    du12.set_mil_float_acc(MIL_FLOAT_ACC6, -0.375).unwrap();
+    assert_dump_du("du12", &du12, "
+0000 a0 00 00 ff 00 00 00 00                         ........");
+    fval = du12.get_mil_float_acc(MIL_FLOAT_ACC6).unwrap();
+    assert::dump_f64("fval", fval, -0.375);
+    du12.set_mil_float_acc(MIL_FLOAT_ACC6, 1.0 + 1.0 / 16777216.0).unwrap();
+    assert_dump_du("du12", &du12, "
+0000 40 00 00 01 40 00 00 00                         @...@...");
+    fval = du12.get_mil_float_acc(MIL_FLOAT_ACC6).unwrap();
+    assert::dump_f64("fval", fval, 1.0 + 1.0 / 16777216.0);
+    let error_message = du12.set_mil_float(0, 5, 1.0).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+
+    // big bit aligned access
+    let mut du13 = du::DU::new_alloc(10);
+    du13.set_big_bits_acc(BBIT_ACC1, 0xffffffffffffffff).unwrap();
+    assert_dump_du("du13", &du13, "
+0000 01 ff ff ff ff ff ff ff fe 00                   ..........");
+    let mut big_val = du13.get_big_bits_acc(BBIT_ACC1).unwrap();
+    assert::dump_u64("big_val", big_val, 0xffffffffffffffff);
+    du13.set_big_bits_acc(BBIT_ACC1, 0x0123456789abcdef).unwrap();
+    assert_dump_du("du13", &du13, "
+0000 00 02 46 8a cf 13 57 9b de 00                   ..F...W...");
+    big_val = du13.get_big_bits_acc(BBIT_ACC1).unwrap();
+    assert::dump_u64("big_val", big_val, 0x0123456789abcdef);
+    du13.set_big_bits_acc(BBIT_ACC2, 0x123456789a).unwrap();
+    assert_dump_du("du13", &du13, "
+0000 00 01 23 45 67 89 a7 9b de 00                   ..#Eg.....");
+    big_val = du13.get_big_bits_acc(BBIT_ACC2).unwrap();
+    assert::dump_u64("big_val", big_val, 0x123456789a);
+    let error_message = du13.set_big_bits_acc(BBIT_ACC2, 0x10000000000).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = du13.get_big_bits(16, 65).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = du13.get_big_bits(20, 64).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+
+    // signed bit aligned access
+    let mut du14 = du::DU::new_alloc(10);
+    du14.set_signed_bits_acc(SBIT_ACC1, -2048).unwrap();
+    assert_dump_du("du14", &du14, "
+0000 08 00 00 00 00 00 00 00 00 00                   ..........");
+    let mut sval = du14.get_signed_bits_acc(SBIT_ACC1).unwrap();
+    assert::dump_i32("sval", sval, -2048);
+    du14.set_signed_bits_acc(SBIT_ACC1, -3).unwrap();
+    assert_dump_du("du14", &du14, "
+0000 0f fd 00 00 00 00 00 00 00 00                   ..........");
+    sval = du14.get_signed_bits_acc(SBIT_ACC1).unwrap();
+    assert::dump_i32("sval", sval, -3);
+    du14.set_signed_bits_acc(SBIT_ACC1, 2047).unwrap();
+    sval = du14.get_signed_bits_acc(SBIT_ACC1).unwrap();
+    assert::dump_i32("sval", sval, 2047);
+    du14.set_signed_bits_acc(SBIT_ACC2, -1).unwrap();
+    assert_dump_du("du14", &du14, "
+0000 07 ff 10 00 00 00 00 00 00 00                   ..........");
+    sval = du14.get_signed_bits_acc(SBIT_ACC2).unwrap();
+    assert::dump_i32("sval", sval, -1);
+    let error_message = du14.set_signed_bits_acc(SBIT_ACC1, 2048).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = du14.set_signed_bits_acc(SBIT_ACC2, 1).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+
+    // big signed bit aligned access
+    let mut du15 = du::DU::new_alloc(10);
+    du15.set_big_signed_bits_acc(BSBIT_ACC1, -2).unwrap();
+    assert_dump_du("du15", &du15, "
+0000 1f ff ff ff ff ff ff ff c0 00                   ..........");
+    let mut bsval = du15.get_big_signed_bits_acc(BSBIT_ACC1).unwrap();
+    assert::dump_i64("bsval", bsval, -2);
+    du15.set_big_signed_bits_acc(BSBIT_ACC2, -34359738368).unwrap();
+    assert_dump_du("du15", &du15, "
+0000 1f ff 80 00 00 00 0f ff c0 00                   ..........");
+    bsval = du15.get_big_signed_bits_acc(BSBIT_ACC2).unwrap();
+    assert::dump_i64("bsval", bsval, -34359738368);
+    let error_message = du15.set_big_signed_bits_acc(BSBIT_ACC2, 34359738368).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+
+    // little endian access
+    let mut du16 = du::DU::new_alloc(8);
+    du16.set_unsigned_le_acc(UINT_LE_ACC4, 0x01020304).unwrap();
+    assert_dump_du("du16", &du16, "
+0000 04 03 02 01 00 00 00 00                         ........");
+    val = du16.get_unsigned_le_acc(UINT_LE_ACC4).unwrap();
+    assert::dump_u32("val", val, 0x01020304);
+    val = du16.get_unsigned_le_acc(UINT_LE_ACC2).unwrap();
+    assert::dump_u32("val", val, 0x0203);
+    // mixed endianness in the same buffer
+    val = du16.get_unsigned(1, 2).unwrap();
+    assert::dump_u32("val", val, 0x0302);
+    let error_message = du16.set_unsigned_le_acc(UINT_LE_ACC2, 0x10000).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    du16.set_big_unsigned_le_acc(BUINT_LE_ACC8, 0x0102030405060708).unwrap();
+    assert_dump_du("du16", &du16, "
+0000 08 07 06 05 04 03 02 01                         ........");
+    bval = du16.get_big_unsigned_le_acc(BUINT_LE_ACC8).unwrap();
+    assert::dump_u64("bval", bval, 0x0102030405060708);
+    du16.set_signed_le_acc(INT_LE_ACC3, -2).unwrap();
+    assert_dump_du("du16", &du16, "
+0000 08 fe ff ff 04 03 02 01                         ........");
+    ival = du16.get_signed_le_acc(INT_LE_ACC3).unwrap();
+    assert::dump_i32("ival", ival, -2);
+    du16.set_big_signed_le_acc(BINT_LE_ACC6, -140737488355328).unwrap();
+    assert_dump_du("du16", &du16, "
+0000 08 fe 00 00 00 00 00 80                         ........");
+    bival = du16.get_big_signed_le_acc(BINT_LE_ACC6).unwrap();
+    assert::dump_i64("bival", bival, -140737488355328);
+    let error_message = du16.set_big_signed_le_acc(BINT_LE_ACC6, 140737488355328).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    du16.set_float_le_acc(FLOAT_LE_ACC4, 1.5).unwrap();
+    assert_dump_du("du16", &du16, "
+0000 00 00 c0 3f 00 00 00 80                         ...?....");
+    fval = du16.get_float_le_acc(FLOAT_LE_ACC4).unwrap();
+    assert::dump_f64("fval", fval, 1.5);
+    du16.set_float_le_acc(FLOAT_LE_ACC8, 1.5).unwrap();
+    assert_dump_du("du16", &du16, "
+0000 00 00 00 00 00 00 f8 3f                         .......?");
+    fval = du16.get_float_le_acc(FLOAT_LE_ACC8).unwrap();
+    assert::dump_f64("fval", fval, 1.5);
+
+    // fixed size string access
+    let mut du17 = du::DU::new_alloc(10);
+    du17.set_string_acc(STR_ACC1, "ABC").unwrap();
+    du17.set_string_acc(STR_ACC2, "xy").unwrap();
+    assert_dump_du("du17", &du17, "
+0000 41 42 43 20 20 20 78 79 00 00                   ABC   xy..");
+    let mut sval = du17.get_string_acc(STR_ACC1).unwrap();
+    assert::dump_string("sval", &sval, "ABC");
+    sval = du17.get_string_acc(STR_ACC2).unwrap();
+    assert::dump_string("sval", &sval, "xy");
+    sval = du17.get_string_acc(STR_ACC3).unwrap();
+    assert::dump_string("sval", &sval, "ABC");
+    du17.set_string_acc(STR_ACC2, "wxyz").unwrap();
+    sval = du17.get_string_acc(STR_ACC2).unwrap();
+    assert::dump_string("sval", &sval, "wxyz");
+    let error_message = du17.set_string_acc(STR_ACC2, "vwxyz").
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = du17.set_string_acc(STR_ACC3, "AB").
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    du17[0] = 0xff;
+    let error_message = du17.get_string_acc(STR_ACC1).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+
+    // variable octet string access
+    let mut du18 = du::DU::new_alloc(10);
+    du18.set_variable_octets_acc(VOCT_ACC1, "ABC".as_bytes()).unwrap();
+    du18.set_variable_octets_acc(VOCT_ACC2, "xyzw".as_bytes()).unwrap();
+    assert_dump_du("du18", &du18, "
+0000 03 41 42 43 00 04 78 79 7a 77                   .ABC..xyzw");
+    let octets = du18.get_variable_octets_acc(VOCT_ACC1).unwrap().to_vec();
+    assert::dump_string("octets", &String::from_utf8(octets).unwrap(), "ABC");
+    let octets = du18.get_variable_octets_acc(VOCT_ACC2).unwrap().to_vec();
+    assert::dump_string("octets", &String::from_utf8(octets).unwrap(), "xyzw");
+    let error_message = du18.set_variable_octets_acc(VOCT_ACC2, "xyzwv".as_bytes()).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = du18.set_variable_octets_acc(VOCT_ACC1, &[0; 256]).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    du18[0] = 10;
+    let error_message = du18.get_variable_octets_acc(VOCT_ACC1).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+
+    // size modifiers on owned data
+    let mut du19 = du::DU::new_clone(&"0123".as_bytes().to_vec());
+    du19.extend_from_slice("4567".as_bytes()).unwrap();
+    assert_dump_du("du19", &du19, "
+0000 30 31 32 33 34 35 36 37                         01234567");
+    let byte_pos = du19.append_zeros(2).unwrap();
+    assert::dump_usize("byte_pos", byte_pos, 8);
+    du19.set_unsigned(byte_pos, 2, 0x4142).unwrap();
+    assert_dump_du("du19", &du19, "
+0000 30 31 32 33 34 35 36 37 41 42                   01234567AB");
+    du19.insert_bytes(0, "hdr".as_bytes()).unwrap();
+    assert_dump_du("du19", &du19, "
+0000 68 64 72 30 31 32 33 34 35 36 37 41 42          hdr01234567AB");
+    du19.remove_range(3..5).unwrap();
+    assert_dump_du("du19", &du19, "
+0000 68 64 72 32 33 34 35 36 37 41 42                hdr234567AB");
+    du19.splice(3..9, "xy".as_bytes()).unwrap();
+    assert_dump_du("du19", &du19, "
+0000 68 64 72 78 79 41 42                            hdrxyAB");
+    du19.truncate(3).unwrap();
+    assert_dump_du("du19", &du19, "
+0000 68 64 72                                        hdr");
+    du19.truncate(10).unwrap();
+    assert::dump_usize("du19.size()", du19.size(), 3);
+    let error_message = du19.insert_bytes(4, "x".as_bytes()).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = du19.remove_range(2..4).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+
+    // size modifiers on wrapped read-write data
+    let mut array20: [u8; 8] = [0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37];
+    // sub-scope to avoid life cycle conflict (read-only + read-write)
+    {
+        let mut du20 = du::DU::new_read_write(&mut array20);
+        du20.splice(1..4, "AB".as_bytes()).unwrap();
+        assert_dump_du("du20", &du20, "
+0000 30 41 42 34 35 36 37                            0AB4567");
+        du20.truncate(5).unwrap();
+        assert_dump_du("du20", &du20, "
+0000 30 41 42 34 35                                  0AB45");
+        let error_message = du20.append_zeros(1).
+            expect_err("expected error did not happen");
+        println!("expected: {}", error_message);
+        let error_message = du20.insert_bytes(0, "x".as_bytes()).
+            expect_err("expected error did not happen");
+        println!("expected: {}", error_message);
+        let error_message = du20.try_resize(6).
+            expect_err("expected error did not happen");
+        println!("expected: {}", error_message);
+        du20.try_resize(4).unwrap();
+        assert::dump_usize("du20.size()", du20.size(), 4);
+    }
+    assert::dump_u8("array20[1]", array20[1], 0x41);
+
+    // size modifiers on wrapped read-only data
+    let mut du21 = du::DU::new_read_only(txt.as_bytes());
+    du21.remove_range(0..11).unwrap();
+    du21.truncate(6).unwrap();
+    assert_dump_du("du21", &du21, "
+0000 68 69 68 69 68 69                               hihihi");
+    let error_message = du21.remove_range(1..2).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = du21.extend_from_slice("x".as_bytes()).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+
+    // size modifiers of the hybrid vector
+    let mut vector22 = du::HybridVector::new_clone(&"0123".as_bytes().to_vec());
+    vector22.extend_from_slice("45".as_bytes()).unwrap();
+    assert::dump_usize("byte_pos", vector22.append_zeros(2).unwrap(), 6);
+    vector22.insert_bytes(0, "x".as_bytes()).unwrap();
+    vector22.remove_range(1..3).unwrap();
+    vector22.truncate(5).unwrap();
+    assert_eq!(vector22.read_only(), "x2345".as_bytes());
+    vector22.try_resize(7).unwrap();
+    assert_eq!(vector22.read_only(), "x2345\0\0".as_bytes());
+    let mut vector23 = du::HybridVector::new_read_only(txt.as_bytes());
+    let error_message = vector23.try_read_write().
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = vector23.try_resize(txt.len() + 1).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+
+    // sub-unit views
+    let mut du22 = du::DU::new_owner(String::from(txt).into_bytes());
+    // sub-scope to avoid life cycle conflict (read-only + read-write)
+    {
+        let du23 = du22.sub_du(11..17).unwrap();
+        assert_dump_du("du23", &du23, "
+0000 68 69 68 69 68 69                               hihihi");
+        let offset = du23.offset_in(du22.buffer_read_only()).unwrap();
+        assert::dump_usize("offset", offset, 11);
+        let du24 = du23.sub_du(2..4).unwrap();
+        let offset = du24.offset_in(du22.buffer_read_only()).unwrap();
+        assert::dump_usize("offset", offset, 13);
+        let error_message = du22.sub_buffer(20..25).
+            expect_err("expected error did not happen");
+        println!("expected: {}", error_message);
+    }
+    {
+        let mut du25 = du22.sub_du_mut(0..4).unwrap();
+        du25.set_string(0, 4, du::StringPadding::NoPadding, "abcd").unwrap();
+        let error_message = du25.set_unsigned(4, 1, 0).
+            expect_err("expected error did not happen");
+        println!("expected: {}", error_message);
+    }
+    assert_dump_du("du22", &du22, "
+0000 61 62 63 64 34 35 36 37 38 39 20 68 69 68 69 68 abcd456789 hihih
+0010 69 20 68 75 68 75 68 75                         i huhuhu");
+    // read-only data cannot be viewed read-write
+    let mut du_read_only = du::DU::new_read_only(du22.buffer_read_only());
+    match du_read_only.sub_du_mut(0..4) {
+        Ok(_) => panic!("expected error did not happen"),
+        Err(error_message) => println!("expected: {}", error_message),
+    }
+    let du26 = du::DU::new_alloc(4);
+    if du26.offset_in(du22.buffer_read_only()).is_some() {
+        panic!("unexpected offset");
+    }
+    // alternative dump formats
+    let du27 = du::DU::new_owner(vec![
+        0x00, 0x01, 0x41, 0x42, 0x80, 0xff, 0x10, 0x20,
+        0x30, 0x40, 0x50, 0x60, 0x70, 0x7e, 0x31, 0x32,
+        0x33, 0x34]);
+    assert::dump_string("du27.dump_hex_str()", &du27.dump_hex_str(),
+        "0001414280ff102030405060707e31323334");
+    assert::dump_string("du27.dump_c_array_str()", &du27.dump_c_array_str("DU27"), "const uint8_t DU27[18] = {
+    0x00, 0x01, 0x41, 0x42, 0x80, 0xff, 0x10, 0x20,
+    0x30, 0x40, 0x50, 0x60, 0x70, 0x7e, 0x31, 0x32,
+    0x33, 0x34
+};");
+    assert::dump_string("du27.dump_rust_array_str()", &du27.dump_rust_array_str("DU27"), "const DU27: [u8; 18] = [
+    0x00, 0x01, 0x41, 0x42, 0x80, 0xff, 0x10, 0x20,
+    0x30, 0x40, 0x50, 0x60, 0x70, 0x7e, 0x31, 0x32,
+    0x33, 0x34
+];");
+    // parse the dump_str format back into a data unit
+    let du28 = du::DU::new_from_dump_str(&du27.dump_str()).unwrap();
+    assert_dump_du("du28", &du28, &du27.dump_str());
+    let du29 = du::DU::new_from_dump_str("EMPTY").unwrap();
+    assert::dump_usize("du29.size()", du29.size(), 0);
+    assert::dump_string("du29.dump_c_array_str()", &du29.dump_c_array_str("DU29"), "/* DU29 is empty */");
+    assert::dump_string("du29.dump_rust_array_str()", &du29.dump_rust_array_str("DU29"), "const DU29: [u8; 0] = [];");
+    let du30 = du::DU::new_from_dump_str("
+0000 31 32 20 20 33                                  12  3").unwrap();
+    assert_dump_du("du30", &du30, "
+0000 31 32 20 20 33                                  12  3");
+    let error_message = du::parse_dump_str("
+0010 31 32 20 20 33                                  12  3").
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = du::parse_dump_str("
+0000 31 3x 20 20 33                                  12  3").
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = du::parse_dump_str("").
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    // structured error kinds
+    let du31 = du::DU::new_alloc(2);
+    let error = du31.get_bits(12, 8).expect_err("expected error did not happen");
+    assert_eq!(*error.kind(), exception::ErrorKind::OutOfBounds {pos: 12, length: 8, size: 2});
+    let error = du::DU::new_alloc(2).set_unsigned(0, 1, 256).expect_err("expected error did not happen");
+    assert_eq!(*error.kind(), exception::ErrorKind::ValueOutOfRange {value: 256, min: 0, max: 255});
+    let error = du::DU::new_alloc(2).set_signed(0, 1, -129).expect_err("expected error did not happen");
+    assert_eq!(*error.kind(), exception::ErrorKind::ValueOutOfRange {value: -129, min: -128, max: 127});
+    let error = du::parse_dump_str("0000 zz").expect_err("expected error did not happen");
+    assert_eq!(*error.kind(), exception::ErrorKind::ParseError {input: String::from("0000 zz")});
 }
@@ -12,6 +12,10 @@
 //*****************************************************************************
 // Utilities - Exception type - Unit tests                                    *
 //*****************************************************************************
+use std::error;
+use std::num;
+use std::sync;
+use test_helpers::assert;
 use util::exception;
 
 fn function_ok() -> Result<(), exception::Exception> {
@@ -26,4 +30,45 @@ pub fn test() {
     function_ok().unwrap();
     let description = function_with_exception().unwrap_err();
     println!("--> description = {}", description);
+    assert_eq!(*description.kind(), exception::ErrorKind::Other);
+    // structured error kinds
+    let error = exception::out_of_bounds("byte_pos/byte_length out of buffer", 4, 2, 5);
+    assert_eq!(*error.kind(), exception::ErrorKind::OutOfBounds {pos: 4, length: 2, size: 5});
+    assert::dump_string("error", &error.to_string(), "byte_pos/byte_length out of buffer");
+    let error = exception::value_out_of_range("value out of range", 256, 0, 255);
+    assert_eq!(*error.kind(), exception::ErrorKind::ValueOutOfRange {value: 256, min: 0, max: 255});
+    let error = exception::invalid_p_field("invalid p-field for CUC time", 0);
+    assert_eq!(*error.kind(), exception::ErrorKind::InvalidPField {p_field: 0});
+    let error = exception::checksum_mismatch("checksum mismatch", 0x1234, 0x4321);
+    assert_eq!(*error.kind(), exception::ErrorKind::ChecksumMismatch {expected: 0x1234, actual: 0x4321});
+    let error = exception::length_mismatch("inconsistent packet length", 5, 7);
+    assert_eq!(*error.kind(), exception::ErrorKind::LengthMismatch {expected: 5, actual: 7});
+    if error::Error::source(&error).is_some() {
+        panic!("unexpected source");
+    }
+    // error chaining
+    let parse_error = "x1".parse::<u32>().unwrap_err();
+    let error = exception::parse_error("invalid number", "x1").with_source(parse_error.clone());
+    assert_eq!(*error.kind(), exception::ErrorKind::ParseError {input: String::from("x1")});
+    let source = error::Error::source(&error).expect("source missing");
+    assert::dump_string("source", &source.to_string(), &parse_error.to_string());
+    if source.downcast_ref::<num::ParseIntError>().is_none() {
+        panic!("unexpected source type");
+    }
+    let error = exception::wrap("invalid number in line 3", error);
+    assert::dump_string("error", &error.to_string(), "invalid number in line 3");
+    assert_eq!(*error.kind(), exception::ErrorKind::ParseError {input: String::from("x1")});
+    let source = error::Error::source(&error).expect("source missing");
+    assert::dump_string("source", &source.to_string(), "invalid number");
+    let source = error::Error::source(source).expect("source missing");
+    assert::dump_string("source", &source.to_string(), &parse_error.to_string());
+    // a shared error keeps its kind and source when it is wrapped
+    let shared_error = sync::Arc::new(error);
+    let error = exception::wrap_shared("cannot read config", shared_error.clone());
+    assert_eq!(*error.kind(), exception::ErrorKind::ParseError {input: String::from("x1")});
+    let source = error::Error::source(&error).expect("source missing");
+    assert::dump_string("source", &source.to_string(), "invalid number in line 3");
+    if error::Error::source(source).is_none() {
+        panic!("source chain is lost");
+    }
 }
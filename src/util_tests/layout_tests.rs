@@ -0,0 +1,127 @@
+//*****************************************************************************
+// (C) 2018, Stefan Korner, Austria                                           *
+//                                                                            *
+// The Space Rust Library is free software; you can redistribute it and/or    *
+// modify it under the terms of the MIT License as published by the           *
+// Massachusetts Institute of Technology.                                     *
+//                                                                            *
+// The Space Rust Library is distributed in the hope that it will be useful,  *
+// but WITHOUT ANY WARRANTY; without even the implied warranty of             *
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the MIT License   *
+// for more details.                                                          *
+//*****************************************************************************
+// Utilities - Field Layout - Unit tests                                      *
+//*****************************************************************************
+use ccsds::c_packet;
+use test_helpers::assert;
+use util::du;
+use util::du::DUintf;
+use util::layout;
+use util_tests::du_tests::assert_dump_du;
+
+///////////////
+// constants //
+///////////////
+
+const HEADER_DEFINITION: &str = "
+# CCSDS packet primary header
+VERSION_NUMBER         bits          0  3
+PACKET_TYPE            bits          3  1
+DATA_FIELD_HEADER_FLAG bits          4  1
+APPLICATION_PROCESS_ID bits          5 11
+SEGMENTATION_FLAGS     bits         16  2
+SEQUENCE_CONTROL_COUNT bits         18 14
+PACKET_LENGTH          unsigned      4  2
+# application data
+TEMPERATURE            signed_le     6  2
+NAME                   string        8  4 space
+";
+
+pub fn test() {
+    // layout loaded from a text definition
+    let layout1 = layout::FieldLayout::from_definition(HEADER_DEFINITION).unwrap();
+    assert::dump_usize("layout1.fields.len()", layout1.fields.len(), 9);
+    let mut du1 = du::DU::new_alloc(12);
+    let mut values1 = layout::FieldValues::new();
+    values1.set("PACKET_TYPE", layout::FieldValue::Unsigned(1));
+    values1.set("APPLICATION_PROCESS_ID", layout::FieldValue::Unsigned(1234));
+    values1.set("SEGMENTATION_FLAGS", layout::FieldValue::Unsigned(3));
+    values1.set("PACKET_LENGTH", layout::FieldValue::Unsigned(5));
+    values1.set("TEMPERATURE", layout::FieldValue::Signed(-2));
+    values1.set("NAME", layout::FieldValue::String(String::from("ab")));
+    layout1.encode(&mut du1, &values1).unwrap();
+    assert_dump_du("du1", &du1, "
+0000 14 d2 c0 00 00 05 fe ff 61 62 20 20             ........ab  ");
+    let values2 = layout1.decode(&du1).unwrap();
+    println!("values2 =\n{}", values2);
+    assert::dump_usize("values2.len()", values2.len(), 9);
+    assert::dump_string("values2.iter().next()",
+        &values2.iter().next().unwrap().0, "VERSION_NUMBER");
+    match values2.get("APPLICATION_PROCESS_ID") {
+        Some(&layout::FieldValue::Unsigned(1234)) => {},
+        _ => panic!("APPLICATION_PROCESS_ID not decoded")
+    }
+    match values2.get("TEMPERATURE") {
+        Some(&layout::FieldValue::Signed(-2)) => {},
+        _ => panic!("TEMPERATURE not decoded")
+    }
+    match values2.get("NAME") {
+        Some(layout::FieldValue::String(name)) => assert::dump_string("NAME", name, "ab"),
+        _ => panic!("NAME not decoded")
+    }
+    // round trip: encoding the decoded values gives the same bytes
+    let mut du2 = du::DU::new_alloc(12);
+    layout1.encode(&mut du2, &values2).unwrap();
+    assert_dump_du("du2", &du2, "
+0000 14 d2 c0 00 00 05 fe ff 61 62 20 20             ........ab  ");
+
+    // layout built in code from the existing accessors, applied to a packet
+    let mut layout2 = layout::FieldLayout::new();
+    layout2.add_field("APID", layout::FieldKind::Bits(
+        c_packet::primary_header::APPLICATION_PROCESS_ID)).unwrap();
+    layout2.add_field("LENGTH", layout::FieldKind::Unsigned(
+        c_packet::primary_header::PACKET_LENGTH)).unwrap();
+    let packet1 = c_packet::Packet::new_read_only(du1.buffer_read_only());
+    let values3 = layout2.decode(&packet1).unwrap();
+    println!("values3 =\n{}", values3);
+    match values3.get("LENGTH") {
+        Some(&layout::FieldValue::Unsigned(5)) => {},
+        _ => panic!("LENGTH not decoded")
+    }
+    if values3.get("NAME").is_some() {
+        panic!("unexpected field NAME");
+    }
+
+    // error cases
+    let error_message = layout2.add_field("APID", layout::FieldKind::Bits(
+        c_packet::primary_header::APPLICATION_PROCESS_ID)).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = layout::FieldLayout::from_definition("A bits 0").
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = layout::FieldLayout::from_definition("A nibbles 0 4").
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let error_message = layout::FieldLayout::from_definition("A unsigned 0 2 space").
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let mut values4 = layout::FieldValues::new();
+    values4.set("UNKNOWN", layout::FieldValue::Unsigned(1));
+    let error_message = layout1.encode(&mut du2, &values4).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let mut values5 = layout::FieldValues::new();
+    values5.set("PACKET_LENGTH", layout::FieldValue::Float(1.0));
+    let error_message = layout1.encode(&mut du2, &values5).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    values5.set("PACKET_LENGTH", layout::FieldValue::Unsigned(0x10000));
+    let error_message = layout1.encode(&mut du2, &values5).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+    let du3 = du::DU::new_alloc(4);
+    let error_message = layout1.decode(&du3).
+        expect_err("expected error did not happen");
+    println!("expected: {}", error_message);
+}
@@ -17,4 +17,5 @@ pub mod config_tests;
 pub mod crc_tests;
 pub mod du_tests;
 pub mod exception_tests;
+pub mod layout_tests;
 pub mod tco_tests;
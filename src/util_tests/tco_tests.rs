@@ -12,6 +12,7 @@
 //*****************************************************************************
 // Utilities - Time Correlation - Unit tests                                  *
 //*****************************************************************************
+use test_helpers::global_state;
 use util::asd_time;
 use util::tco;
 
@@ -20,6 +21,8 @@ use util::tco;
 ///////////////
 
 pub fn test() {
+    // the deltas depend on the global configuration
+    let _global_state_guard = global_state::lock();
     println!("ERT delta = {}", tco::get_ert_delta().num_seconds());
     println!("OBT delta = {}", tco::get_obt_delta().num_seconds());
     let tai_zero = asd_time::parse_time(tco::TAI_MISSION_EPOCH).unwrap();